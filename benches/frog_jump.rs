@@ -0,0 +1,236 @@
+//! This benchmark compares the scaling of [`ParallelDFS`] with the previous
+//! implementation on the [403. Frog Jump](https://leetcode.com/problems/frog-jump/)
+//! LeetCode problem
+//!
+//! The previous implementation split the stack between the threads of a
+//! [`rayon`] pool at each round and waited for every chunk before the next
+//! round. The current one uses work-stealing tasks.
+//!
+//! The number of stones can be set using the `FROG_STONES` environment
+//! variable and the thread counts using `FROG_THREADS`, e.g.
+//! `FROG_THREADS=1,2,4,8 cargo bench --features rayon`.
+
+use nodify::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rayon::ThreadPoolBuilder;
+
+use std::{
+    env,
+    hash::{Hash, Hasher},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// The number of runs of each measure whose median is reported
+const RUNS: usize = 5;
+
+/// A node representing the frog state
+#[derive(Clone, Copy, Debug, Eq)]
+struct FrogNode<'a> {
+    position: usize,
+    speed: usize,
+    has_stone: &'a [bool],
+}
+
+/// [`PartialEq`] trait implementation
+///
+/// Only the frog's position and speed are used.
+impl PartialEq for FrogNode<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.speed == other.speed
+    }
+}
+
+/// [`Hash`] trait implementation
+///
+/// Only the frog's position and speed are used.
+impl Hash for FrogNode<'_> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (self.position, self.speed).hash(state);
+    }
+}
+
+impl Node for FrogNode<'_> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let small_speed = self.speed - 1;
+        let big_speed = self.speed + 1;
+        let big_position = self.position + big_speed;
+
+        Some((big_position, big_speed))
+            .into_iter()
+            .chain((small_speed > 0).then_some((self.position + small_speed, small_speed)))
+            .chain(Some((self.position + self.speed, self.speed)))
+            .filter(|&(p, _)| self.has_stone.get(p).copied().unwrap_or(false))
+            .map(move |(position, speed)| Self {
+                position,
+                speed,
+                has_stone: self.has_stone,
+            })
+    }
+}
+
+/// The implementation of [`ParallelDFS`] preceding the work-stealing tasks
+mod previous {
+    use nodify::prelude::*;
+    use rayon::prelude::*;
+    use std::{collections::LinkedList, hash::Hash};
+
+    type HashSet<K> = dashmap::DashSet<K, ahash::RandomState>;
+
+    /// The number of expansions after which a chunk is given back
+    const THRESHOLD: usize = 50_000;
+
+    /// Check whether some node reachable from `root` verifies `pred`
+    ///
+    /// The current thread pool is used.
+    pub fn contains<N, P>(root: N, pred: P) -> bool
+    where
+        N: Copy + Eq + Hash + Node + Send + Sync,
+        P: Fn(N) -> bool + Sync,
+    {
+        let is_visited = HashSet::default();
+        let max_len = rayon::current_num_threads();
+
+        let mut to_visit = vec![root];
+
+        while !to_visit.is_empty() {
+            let len = to_visit.len();
+
+            if len < max_len {
+                match next_until(&is_visited, to_visit, &pred) {
+                    Some(next) => to_visit = next,
+                    None => return true,
+                }
+            } else {
+                let next = to_visit
+                    .par_drain(len.saturating_sub(max_len)..)
+                    .chunks(1)
+                    .try_fold(LinkedList::new, |mut next, to_visit| {
+                        next.push_back(next_until(&is_visited, to_visit, &pred)?);
+                        Some(next)
+                    })
+                    .try_reduce(LinkedList::new, |mut lhs, mut rhs| {
+                        lhs.append(&mut rhs);
+                        Some(lhs)
+                    });
+
+                match next {
+                    Some(next) => to_visit.extend(next.into_iter().flatten()),
+                    None => return true,
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Explore `to_visit` for at most [`THRESHOLD`] expansions
+    ///
+    /// # Return
+    ///
+    /// The nodes left to visit or `None` if a node verifying `pred` is found.
+    fn next_until<N, P>(is_visited: &HashSet<N>, mut to_visit: Vec<N>, pred: &P) -> Option<Vec<N>>
+    where
+        N: Copy + Eq + Hash + Node,
+        P: Fn(N) -> bool,
+    {
+        for _ in 0..THRESHOLD {
+            let Some(node) = to_visit.pop() else {
+                break;
+            };
+
+            if is_visited.insert(node) {
+                for next in node.outgoing() {
+                    if is_visited.contains(&next) {
+                        continue;
+                    }
+
+                    if pred(next) {
+                        return None;
+                    }
+
+                    to_visit.push(next);
+                }
+            }
+        }
+
+        Some(to_visit)
+    }
+}
+
+/// Get the median duration of [`RUNS`] calls to `f`
+fn measure(mut f: impl FnMut() -> bool) -> (bool, Duration) {
+    let mut found = false;
+
+    let mut durations = Vec::from_iter((0..RUNS).map(|_| {
+        let start = Instant::now();
+        found = f();
+        start.elapsed()
+    }));
+
+    durations.sort_unstable();
+    (found, durations[RUNS / 2])
+}
+
+fn main() {
+    let stones = env::var("FROG_STONES")
+        .ok()
+        .and_then(|stones| stones.parse().ok())
+        .unwrap_or(20_000);
+
+    let threads = env::var("FROG_THREADS")
+        .ok()
+        .map(|threads| {
+            Vec::from_iter(
+                threads
+                    .split(',')
+                    .map(|count| count.parse::<usize>().expect("invalid thread count")),
+            )
+        })
+        .unwrap_or_else(|| vec![1, 2, 4, 8]);
+
+    let mut rng = StdRng::seed_from_u64(403);
+
+    // The first stones are all kept so that the frog can gain some speed.
+    let has_stone = (0..stones)
+        .map(|position| position < 16 || rng.random_bool(0.8))
+        .collect::<Vec<_>>();
+
+    let root = FrogNode {
+        position: 0,
+        speed: 1,
+        has_stone: &has_stone,
+    };
+
+    // The last stone is never reached so that the whole graph is explored.
+    let pred = |FrogNode { position, .. }| position == has_stone.len();
+
+    let nodes = root.to_process::<DFS<_>>().reachable().len();
+
+    println!("{stones} stones, {nodes} reachable nodes, median of {RUNS} runs");
+    println!("{:>7} {:>12} {:>12}", "threads", "previous", "current");
+
+    for count in threads {
+        let pool = Arc::new(
+            ThreadPoolBuilder::new()
+                .num_threads(count)
+                .build()
+                .expect("unable to build the thread pool"),
+        );
+
+        let (previous_found, previous) =
+            measure(|| pool.install(|| previous::contains(root, pred)));
+
+        let process = root
+            .to_process::<ParallelDFS<_>>()
+            .with_thread_pool(Arc::clone(&pool));
+
+        let (current_found, current) = measure(|| process.contains(pred));
+
+        assert_eq!(previous_found, current_found);
+        println!("{count:>7} {previous:>12.2?} {current:>12.2?}");
+    }
+}
@@ -0,0 +1,158 @@
+//! Crawl the pages served by a local mock HTTP server
+//!
+//! In this example, the outgoing nodes of [`Page`] are fetched asynchronously and several requests
+//! are kept in flight. The first failed request stops the crawl and its error is reported.
+
+use nodify::prelude::*;
+use std::{
+    hash::{Hash, Hasher},
+    io,
+    net::SocketAddr,
+    time::Instant,
+};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+
+/// The number of pages served by the mock server
+const PAGE_COUNT: u32 = 1_000;
+
+/// A node representing a crawled page
+///
+/// Moreover, the `server` address is used to fetch the outgoing pages.
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct Page {
+    /// The page identifier
+    pub id: u32,
+
+    /// The address of the server serving the page
+    pub server: SocketAddr,
+}
+
+/// [`PartialEq`] trait implementation
+///
+/// Only the page identifier is used.
+impl PartialEq for Page {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/// [`Hash`] trait implementation
+///
+/// Only the page identifier is used.
+impl Hash for Page {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.id.hash(state);
+    }
+}
+
+impl Page {
+    /// Fetch the identifiers of the pages linked by the current one
+    async fn links(self) -> io::Result<Vec<u32>> {
+        let mut stream = TcpStream::connect(self.server).await?;
+        let request = format!("GET /{} HTTP/1.0\r\n\r\n", self.id);
+        stream.write_all(request.as_bytes()).await?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed response"))?;
+
+        if !head.starts_with("HTTP/1.0 200") {
+            let status = head.lines().next().unwrap_or_default();
+            let error = format!("page {}: {status}", self.id);
+            return Err(io::Error::new(io::ErrorKind::NotFound, error));
+        }
+
+        Ok(body.lines().filter_map(|line| line.parse().ok()).collect())
+    }
+}
+
+/// [`ToValue`] implementation for [`Page`]
+///
+/// As [`Page`] does not implement [`Node`], this implementation allows the predicates to take a
+/// [`Page`].
+impl ToValue<Self> for Page {
+    fn to_value(self) -> Self {
+        self
+    }
+}
+
+impl AsyncTryNode for Page {
+    type Error = io::Error;
+
+    async fn try_async_outgoing(self) -> io::Result<impl Iterator<Item = Self>> {
+        let links = self.links().await?;
+
+        Ok(links.into_iter().map(move |id| Self {
+            id,
+            server: self.server,
+        }))
+    }
+}
+
+/// Serve the mock pages
+///
+/// The page `n` links to the pages `2n + 1` and `3n + 2` modulo [`PAGE_COUNT`].
+async fn serve(listener: TcpListener) -> io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+
+        tokio::spawn(async move {
+            let mut stream = BufReader::new(stream);
+            let mut request = String::new();
+            stream.read_line(&mut request).await?;
+
+            let id = request
+                .split_whitespace()
+                .nth(1)
+                .and_then(|path| path.trim_start_matches('/').parse::<u32>().ok());
+
+            let response = match id {
+                Some(id) if id < PAGE_COUNT => {
+                    let body = format!(
+                        "{}\n{}\n",
+                        (2 * id + 1) % PAGE_COUNT,
+                        (3 * id + 2) % PAGE_COUNT
+                    );
+
+                    format!("HTTP/1.0 200 OK\r\n\r\n{body}")
+                }
+
+                _ => "HTTP/1.0 404 Not Found\r\n\r\n".to_owned(),
+            };
+
+            stream.get_mut().write_all(response.as_bytes()).await
+        });
+    }
+}
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let server = listener.local_addr()?;
+    tokio::spawn(serve(listener));
+
+    let root = Page { id: 0, server };
+    let start = Instant::now();
+
+    let found = AsyncBFS::from_node(root)
+        .with_concurrency(32)
+        .try_find_any(|Page { id, .. }| id == 777)
+        .await?;
+
+    let stop = start.elapsed();
+
+    println!("{root:?}");
+    println!("=> {found:?} ({stop:?})");
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Share owned _nodifyied_ nodes between threads
+
+use nodify::prelude::*;
+use std::{iter::once, thread};
+
+/// The outgoing states of a [Collatz](https://en.wikipedia.org/wiki/Collatz_conjecture)
+/// state
+fn collatz(n: u64) -> impl Iterator<Item = u64> {
+    once(if n.is_multiple_of(2) {
+        n / 2
+    } else {
+        3 * n + 1
+    })
+    .filter(move |_| n != 1)
+}
+
+fn main() {
+    let unweighted = NodifyiedBuilder::new(collatz as fn(u64) -> _);
+    let weighted = NodifyiedBuilder::weighted(|n: u64| collatz(n).map(|next| (1u64, next)));
+
+    // The built nodes do not borrow the builders and can be moved to other
+    // threads.
+    let roots = [27, 97, 871].map(|n| (unweighted.build_owned(n), weighted.build_owned(n)));
+
+    let handles = roots.map(|(unweighted, weighted)| {
+        thread::spawn(move || {
+            let found = unweighted
+                .to_process::<ParallelDFS<_>>()
+                .contains(|n: u64| n == 1);
+
+            let steps = weighted
+                .to_process::<DeltaStepping<_, u64>>()
+                .with_delta(1)
+                .find_path(|n: u64| n == 1)
+                .map_or(0, |path| path.len() - 1);
+
+            (found, steps)
+        })
+    });
+
+    for (n, handle) in [27, 97, 871].into_iter().zip(handles) {
+        let (found, steps) = handle.join().unwrap();
+        println!("{n}: reaches 1 = {found}, steps = {steps}");
+    }
+}
@@ -0,0 +1,41 @@
+//! _Nodify_ the [Knapsack problem](https://en.wikipedia.org/wiki/Knapsack_problem)
+//! without a custom node type
+
+use nodify::prelude::*;
+use std::iter::once;
+
+fn main() {
+    // (value, weight) of every item
+    let items = [(1, 1), (7, 2), (11, 3)];
+    let capacity = 5;
+    let max_value = 11;
+
+    // A state is the index of the next item, the remaining capacity and the
+    // value of the taken items. The weights are decreasing as functions of the
+    // item values so that the first solution is the most valuable one.
+    let builder = NodifyiedBuilder::weighted(|(index, capacity, value): (usize, u32, u32)| {
+        items
+            .get(index)
+            .map(|&(item_value, item_weight)| {
+                once((max_value, (index + 1, capacity, value))).chain(
+                    (item_weight <= capacity).then(|| {
+                        let state = (index + 1, capacity - item_weight, value + item_value);
+                        (max_value - item_value, state)
+                    }),
+                )
+            })
+            .into_iter()
+            .flatten()
+    });
+
+    let (_, remaining, value) = builder
+        .build((0, capacity, 0))
+        .to_process::<DeltaStepping<_, _>>()
+        .with_delta(2)
+        .find_first(|(index, _, _): (usize, u32, u32)| index == items.len())
+        .unwrap()
+        .to_value();
+
+    assert_eq!((value, capacity - remaining), (18, 5));
+    println!("value = {value}, weight = {}", capacity - remaining);
+}
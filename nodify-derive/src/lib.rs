@@ -0,0 +1,319 @@
+//! The derive macros of [nodify](https://github.com/beatussum/nodify)
+//!
+//! # Description
+//!
+//! This crate contains
+//! - [`Node`](macro@Node) which derives the `Node` trait, and
+//! - [`Weighted`](macro@Weighted) which derives the `Weighted` and the `Node`
+//!   traits.
+//!
+//! Both macros forward to a successor method of the node type and implement
+//! [`PartialEq`], [`Eq`] and [`Hash`](std::hash::Hash) using the fields which
+//! are not marked with `#[nodify(skip)]`. Hence, context fields (e.g. the
+//! description of the explored problem) can be left out of the node identity.
+//!
+//! This crate should be used through the `derive` feature of nodify which
+//! re-exports its macros.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Index, Member, Path, Result, Type};
+
+/// Derive the `Node` trait
+///
+/// The `Node` implementation forwards to the method given through the
+/// `#[nodify(outgoing = ...)]` attribute. This method takes `self` and returns
+/// an iterator over the outgoing nodes.
+///
+/// [`PartialEq`], [`Eq`] and [`Hash`](std::hash::Hash) are implemented as
+/// well using the fields which are not marked with `#[nodify(skip)]`.
+///
+/// # Attributes
+///
+/// - `#[nodify(outgoing = method)]` - The successor method (a path with a
+///   single segment is looked up in `Self`)
+/// - `#[nodify(skip)]` - Leave the marked field out of the node identity
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, Node)]
+/// #[nodify(outgoing = next)]
+/// struct Counter<'a> {
+///     value: u32,
+///
+///     #[nodify(skip)]
+///     steps: &'a [u32],
+/// }
+///
+/// impl Counter<'_> {
+///     fn next(self) -> impl Iterator<Item = Self> {
+///         self.steps.iter().map(move |step| Self {
+///             value: (self.value + step) % 100,
+///             ..self
+///         })
+///     }
+/// }
+///
+/// let root = Counter {
+///     value: 0,
+///     steps: &[7, 13],
+/// };
+///
+/// let found = root
+///     .to_process::<DFS<_>>()
+///     .contains(|Counter { value, .. }| value == 42);
+///
+/// assert!(found);
+/// ```
+#[proc_macro_derive(Node, attributes(nodify))]
+pub fn derive_node(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_node(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive the `Weighted` and the `Node` traits
+///
+/// The `Weighted` implementation forwards to the method given through the
+/// `#[nodify(weighted_outgoing = ...)]` attribute. This method takes `self`
+/// and returns an iterator over the weighted outgoing nodes whose weight type
+/// is given through the `#[nodify(weight = ...)]` attribute. The `Node`
+/// implementation discards the weights.
+///
+/// [`PartialEq`], [`Eq`] and [`Hash`](std::hash::Hash) are implemented as
+/// well using the fields which are not marked with `#[nodify(skip)]`.
+///
+/// # Attributes
+///
+/// - `#[nodify(weighted_outgoing = method)]` - The successor method (a path
+///   with a single segment is looked up in `Self`)
+/// - `#[nodify(weight = Type)]` - The weight type
+/// - `#[nodify(skip)]` - Leave the marked field out of the node identity
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, Weighted)]
+/// #[nodify(weighted_outgoing = next, weight = u32)]
+/// struct Position(u32);
+///
+/// impl Position {
+///     fn next(self) -> impl Iterator<Item = (u32, Self)> {
+///         [(1, Self(self.0 + 1)), (5, Self(self.0 * 2))].into_iter()
+///     }
+/// }
+///
+/// let outgoing = Position(3)
+///     .weighted_outgoing()
+///     .map(|(weight, Position(i))| (weight, i))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(outgoing, [(1, 4), (5, 6)]);
+/// assert_eq!(Position(3).outgoing().count(), 2);
+/// ```
+#[proc_macro_derive(Weighted, attributes(nodify))]
+pub fn derive_weighted(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_weighted(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The options given through the `#[nodify(...)]` attributes of a node type
+#[derive(Default)]
+struct Options {
+    outgoing: Option<Path>,
+    weighted_outgoing: Option<Path>,
+    weight: Option<Type>,
+}
+
+impl Options {
+    /// Parse the `#[nodify(...)]` attributes among `attrs`
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("nodify")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("outgoing") {
+                    options.outgoing = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("weighted_outgoing") {
+                    options.weighted_outgoing = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("weight") {
+                    options.weight = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown nodify attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Expand `#[derive(Node)]`
+fn expand_node(input: &DeriveInput) -> Result<TokenStream2> {
+    let outgoing = Options::parse(&input.attrs)?
+        .outgoing
+        .ok_or_else(|| Error::new_spanned(&input.ident, "missing `#[nodify(outgoing = ...)]`"))?;
+
+    let outgoing = method(outgoing);
+    let identity = expand_identity(input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #identity
+
+        impl #impl_generics ::nodify::Node for #name #ty_generics #where_clause {
+            fn outgoing(self) -> impl ::core::iter::Iterator<Item = Self> {
+                #outgoing(self)
+            }
+        }
+    })
+}
+
+/// Expand `#[derive(Weighted)]`
+fn expand_weighted(input: &DeriveInput) -> Result<TokenStream2> {
+    let options = Options::parse(&input.attrs)?;
+
+    let weighted_outgoing = options.weighted_outgoing.ok_or_else(|| {
+        Error::new_spanned(&input.ident, "missing `#[nodify(weighted_outgoing = ...)]`")
+    })?;
+
+    let weight = options
+        .weight
+        .ok_or_else(|| Error::new_spanned(&input.ident, "missing `#[nodify(weight = ...)]`"))?;
+
+    let weighted_outgoing = method(weighted_outgoing);
+    let identity = expand_identity(input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #identity
+
+        impl #impl_generics ::nodify::Weighted for #name #ty_generics #where_clause {
+            type Weight = #weight;
+
+            fn weighted_outgoing(
+                self,
+            ) -> impl ::core::iter::Iterator<Item = (Self::Weight, Self)> {
+                #weighted_outgoing(self)
+            }
+        }
+
+        impl #impl_generics ::nodify::Node for #name #ty_generics #where_clause {
+            fn outgoing(self) -> impl ::core::iter::Iterator<Item = Self> {
+                ::core::iter::Iterator::map(
+                    ::nodify::Weighted::weighted_outgoing(self),
+                    |(_, node)| node,
+                )
+            }
+        }
+    })
+}
+
+/// Expand the [`PartialEq`], [`Eq`] and [`Hash`](std::hash::Hash)
+/// implementations of a node type
+///
+/// Only the fields which are not marked with `#[nodify(skip)]` are used and
+/// bounded.
+fn expand_identity(input: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        let message = "nodify derive macros only support structs";
+        return Err(Error::new_spanned(&input.ident, message));
+    };
+
+    let mut members = Vec::default();
+    let mut types = Vec::default();
+
+    for (index, field) in data.fields.iter().enumerate() {
+        let mut skip = false;
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("nodify"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown nodify field attribute"))
+                }
+            })?;
+        }
+
+        if !skip {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+
+            members.push(member);
+            types.push(&field.ty);
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let predicates = Vec::from_iter(
+        input
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.predicates),
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics
+        where
+            #(#predicates,)*
+            #(#types: ::core::cmp::PartialEq,)*
+        {
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& self.#members == other.#members)*
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #ty_generics
+        where
+            #(#predicates,)*
+            #(#types: ::core::cmp::Eq,)*
+        {
+        }
+
+        impl #impl_generics ::core::hash::Hash for #name #ty_generics
+        where
+            #(#predicates,)*
+            #(#types: ::core::hash::Hash,)*
+        {
+            fn hash<__NodifyHasher: ::core::hash::Hasher>(&self, state: &mut __NodifyHasher) {
+                #(::core::hash::Hash::hash(&self.#members, state);)*
+            }
+        }
+    })
+}
+
+/// Get the function called by a derived successor method
+///
+/// A path with a single segment is looked up in `Self`.
+fn method(path: Path) -> TokenStream2 {
+    match path.get_ident() {
+        Some(ident) => quote!(Self::#ident),
+        None => quote!(#path),
+    }
+}
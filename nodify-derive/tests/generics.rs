@@ -0,0 +1,34 @@
+use nodify::prelude::*;
+use std::iter::once;
+
+/// A node whose generic parameter has the name of the usual hasher one
+#[derive(Clone, Copy, Debug, Node)]
+#[nodify(outgoing = next)]
+struct Wrapper<H: Copy + Into<u8>> {
+    value: u8,
+
+    #[nodify(skip)]
+    step: H,
+}
+
+impl<H: Copy + Into<u8>> Wrapper<H> {
+    fn next(self) -> impl Iterator<Item = Self> {
+        once(Self {
+            value: self.value.wrapping_add(self.step.into()),
+            ..self
+        })
+    }
+}
+
+#[test]
+fn generic_named_like_the_hasher() {
+    let root = Wrapper {
+        value: 0,
+        step: 3_u8,
+    };
+
+    assert!(
+        root.to_process::<DFS<_>>()
+            .contains(|node: Wrapper<_>| node.value == 42)
+    );
+}
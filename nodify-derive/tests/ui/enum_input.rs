@@ -0,0 +1,19 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Copy, Node)]
+#[nodify(outgoing = next)]
+enum Light {
+    Red,
+    Green,
+}
+
+impl Light {
+    fn next(self) -> impl Iterator<Item = Self> {
+        std::iter::once(match self {
+            Self::Red => Self::Green,
+            Self::Green => Self::Red,
+        })
+    }
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Copy, Node)]
+struct Counter(u32);
+
+fn main() {}
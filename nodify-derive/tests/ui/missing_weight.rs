@@ -0,0 +1,13 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Copy, Weighted)]
+#[nodify(weighted_outgoing = next)]
+struct Position(u32);
+
+impl Position {
+    fn next(self) -> impl Iterator<Item = (u32, Self)> {
+        std::iter::once((1, Self(self.0 + 1)))
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Copy, Weighted)]
+#[nodify(weight = u32)]
+struct Position(u32);
+
+fn main() {}
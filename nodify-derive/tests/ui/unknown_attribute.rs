@@ -0,0 +1,13 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Copy, Node)]
+#[nodify(outgoing = next, incoming = previous)]
+struct Counter(u32);
+
+impl Counter {
+    fn next(self) -> impl Iterator<Item = Self> {
+        std::iter::once(Self(self.0 + 1))
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Copy, Node)]
+#[nodify(outgoing = next)]
+struct Counter {
+    value: u32,
+
+    #[nodify(ignore)]
+    step: u32,
+}
+
+impl Counter {
+    fn next(self) -> impl Iterator<Item = Self> {
+        std::iter::once(Self {
+            value: self.value + self.step,
+            ..self
+        })
+    }
+}
+
+fn main() {}
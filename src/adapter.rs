@@ -0,0 +1,1599 @@
+//! Many different node adapters
+//!
+//! This module contains several different [node](Node) adapters wrapping an
+//! existing [node](Node) in order to alter the explored graph.
+
+use super::{
+    Canonical, DynNode, Heuristic, Incoming, Labeled, Node, ToValue, TryNode, TryWeighted,
+    Weighted, WeightedIncoming, WeightedLabeled,
+};
+
+use std::{
+    any::Any,
+    collections::hash_map::Entry,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+type HashMap<K, V> = std::collections::HashMap<K, V, ahash::RandomState>;
+
+/// A [node](Node) whose outgoing nodes are canonicalized
+///
+/// This `struct` is created by
+/// [`.canonicalized()`](Canonical::canonicalized). See its documentation for
+/// more information.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Canonicalized<N> {
+    node: N,
+}
+
+impl<N: Canonical> Canonicalized<N> {
+    /// Create a new [`Canonicalized`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node which is canonicalized
+    ///
+    /// # Return
+    ///
+    /// A new [`Canonicalized`] node wrapping the canonical representative of
+    /// `node`.
+    pub fn new(node: N) -> Self {
+        Self {
+            node: node.canonical(),
+        }
+    }
+}
+
+impl<N> Canonicalized<N> {
+    /// Get the wrapped canonical representative
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+/// [`ToValue`] implementation for [`Canonicalized`]
+///
+/// This implementation allows casting to the wrapped canonical representative.
+impl<N> ToValue<N> for Canonicalized<N> {
+    fn to_value(self) -> N {
+        self.node
+    }
+}
+
+/// [`Node`] implementation for [`Canonicalized`]
+///
+/// The outgoing nodes of the wrapped node are canonicalized.
+impl<N: Canonical + Node> Node for Canonicalized<N> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.node.outgoing().map(Self::new)
+    }
+}
+
+/// [`Weighted`] implementation for [`Canonicalized`]
+///
+/// The outgoing nodes of the wrapped node are canonicalized while the weights
+/// are kept as is.
+impl<N: Canonical + Weighted> Weighted for Canonicalized<N> {
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_outgoing()
+            .map(|(weight, node)| (weight, Self::new(node)))
+    }
+}
+
+/// [`Labeled`] implementation for [`Canonicalized`]
+///
+/// The outgoing nodes of the wrapped node are canonicalized while the labels
+/// are kept as is.
+impl<N: Canonical + Labeled> Labeled for Canonicalized<N> {
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        self.node
+            .labeled_outgoing()
+            .map(|(label, node)| (label, Self::new(node)))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`Canonicalized`]
+///
+/// The outgoing nodes of the wrapped node are canonicalized while the weights
+/// and the labels are kept as is.
+impl<N: Canonical + WeightedLabeled> WeightedLabeled for Canonicalized<N> {
+    type Weight = N::Weight;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        self.node
+            .weighted_labeled_outgoing()
+            .map(|(weight, label, node)| (weight, label, Self::new(node)))
+    }
+}
+
+/// [`TryNode`] implementation for [`Canonicalized`]
+///
+/// The successfully generated outgoing nodes of the wrapped node are
+/// canonicalized.
+impl<N: Canonical + TryNode> TryNode for Canonicalized<N> {
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        self.node.try_outgoing().map(|node| node.map(Self::new))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`Canonicalized`]
+///
+/// The successfully generated outgoing nodes of the wrapped node are
+/// canonicalized while the weights are kept as is.
+impl<N: Canonical + TryWeighted> TryWeighted for Canonicalized<N> {
+    type Weight = N::Weight;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        self.node
+            .try_weighted_outgoing()
+            .map(|edge| edge.map(|(weight, node)| (weight, Self::new(node))))
+    }
+}
+
+/// [`Incoming`] implementation for [`Canonicalized`]
+///
+/// The incoming nodes of the wrapped node are canonicalized.
+impl<N: Canonical + Incoming> Incoming for Canonicalized<N> {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        self.node.incoming().map(Self::new)
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`Canonicalized`]
+///
+/// The incoming nodes of the wrapped node are canonicalized while the weights
+/// are kept as is.
+impl<N: Canonical + WeightedIncoming> WeightedIncoming for Canonicalized<N> {
+    type Weight = N::Weight;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_incoming()
+            .map(|(weight, node)| (weight, Self::new(node)))
+    }
+}
+
+/// A [node](Node) whose edges are reversed
+///
+/// This `struct` is created by [`.reversed()`](Incoming::reversed). See its
+/// documentation for more information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reversed<N> {
+    node: N,
+}
+
+impl<N> Reversed<N> {
+    /// Create a new [`Reversed`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node whose edges are reversed
+    ///
+    /// # Return
+    ///
+    /// A new [`Reversed`] node wrapping `node`.
+    pub fn new(node: N) -> Self {
+        Self { node }
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+/// [`ToValue`] implementation for [`Reversed`]
+///
+/// This implementation allows casting to the wrapped node.
+impl<N> ToValue<N> for Reversed<N> {
+    fn to_value(self) -> N {
+        self.node
+    }
+}
+
+/// [`Node`] implementation for [`Reversed`]
+///
+/// The incoming nodes of the wrapped node are used as outgoing nodes.
+impl<N: Incoming> Node for Reversed<N> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.node.incoming().map(Self::new)
+    }
+}
+
+/// [`Incoming`] implementation for [`Reversed`]
+///
+/// The outgoing nodes of the wrapped node are used as incoming nodes.
+impl<N: Node> Incoming for Reversed<N> {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        self.node.outgoing().map(Self::new)
+    }
+}
+
+/// [`Weighted`] implementation for [`Reversed`]
+///
+/// The incoming edges of the wrapped node are used as outgoing edges.
+impl<N: WeightedIncoming> Weighted for Reversed<N> {
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_incoming()
+            .map(|(weight, node)| (weight, Self::new(node)))
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`Reversed`]
+///
+/// The outgoing edges of the wrapped node are used as incoming edges.
+impl<N: Weighted> WeightedIncoming for Reversed<N> {
+    type Weight = N::Weight;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_outgoing()
+            .map(|(weight, node)| (weight, Self::new(node)))
+    }
+}
+
+/// A [node](Node) whose edges are filtered
+///
+/// This `struct` is created by [`.filter_outgoing()`](Node::filter_outgoing).
+/// See its documentation for more information.
+#[derive(Clone, Copy)]
+pub struct FilterOutgoing<N, P> {
+    node: N,
+    pred: P,
+}
+
+impl<N, P> FilterOutgoing<N, P> {
+    /// Create a new [`FilterOutgoing`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node whose edges are filtered
+    /// - `pred` - The predicate called with the source and the target of every
+    ///   edge
+    ///
+    /// # Return
+    ///
+    /// A new [`FilterOutgoing`] node wrapping `node`.
+    pub fn new(node: N, pred: P) -> Self {
+        Self { node, pred }
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+impl<N: Debug, P> Debug for FilterOutgoing<N, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilterOutgoing")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`PartialEq`] implementation for [`FilterOutgoing`]
+///
+/// The wrapped node is transparently compared with.
+impl<N: PartialEq, P> PartialEq for FilterOutgoing<N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.eq(&other.node)
+    }
+}
+
+/// [`Eq`] implementation for [`FilterOutgoing`]
+///
+/// The wrapped node is transparently compared with.
+impl<N: Eq, P> Eq for FilterOutgoing<N, P> {}
+
+/// [`Hash`] implementation for [`FilterOutgoing`]
+///
+/// The wrapped node is transparently hashed.
+impl<N: Hash, P> Hash for FilterOutgoing<N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+    }
+}
+
+/// [`ToValue`] implementation for [`FilterOutgoing`]
+///
+/// This implementation allows casting to the wrapped node.
+impl<N, P> ToValue<N> for FilterOutgoing<N, P> {
+    fn to_value(self) -> N {
+        self.node
+    }
+}
+
+/// [`Node`] implementation for [`FilterOutgoing`]
+///
+/// Only the outgoing nodes verifying the predicate are kept.
+impl<N, P> Node for FilterOutgoing<N, P>
+where
+    N: Copy + Node,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.node
+            .outgoing()
+            .filter(move |next| (self.pred)(&self.node, next))
+            .map(move |node| Self::new(node, self.pred))
+    }
+}
+
+/// [`Weighted`] implementation for [`FilterOutgoing`]
+///
+/// Only the outgoing edges verifying the predicate are kept.
+impl<N, P> Weighted for FilterOutgoing<N, P>
+where
+    N: Copy + Weighted,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_outgoing()
+            .filter(move |(_, next)| (self.pred)(&self.node, next))
+            .map(move |(weight, node)| (weight, Self::new(node, self.pred)))
+    }
+}
+
+/// [`Labeled`] implementation for [`FilterOutgoing`]
+///
+/// Only the outgoing edges verifying the predicate are kept with their labels.
+impl<N, P> Labeled for FilterOutgoing<N, P>
+where
+    N: Copy + Labeled,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        self.node
+            .labeled_outgoing()
+            .filter(move |(_, next)| (self.pred)(&self.node, next))
+            .map(move |(label, node)| (label, Self::new(node, self.pred)))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`FilterOutgoing`]
+///
+/// Only the outgoing edges verifying the predicate are kept with their weights
+/// and labels.
+impl<N, P> WeightedLabeled for FilterOutgoing<N, P>
+where
+    N: Copy + WeightedLabeled,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    type Weight = N::Weight;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        self.node
+            .weighted_labeled_outgoing()
+            .filter(move |(_, _, next)| (self.pred)(&self.node, next))
+            .map(move |(weight, label, node)| (weight, label, Self::new(node, self.pred)))
+    }
+}
+
+/// [`TryNode`] implementation for [`FilterOutgoing`]
+///
+/// Only the successfully generated outgoing nodes verifying the predicate are
+/// kept along with the errors.
+impl<N, P> TryNode for FilterOutgoing<N, P>
+where
+    N: Copy + TryNode,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        self.node
+            .try_outgoing()
+            .filter(move |next| {
+                next.as_ref()
+                    .map_or(true, |next| (self.pred)(&self.node, next))
+            })
+            .map(move |node| node.map(|node| Self::new(node, self.pred)))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`FilterOutgoing`]
+///
+/// Only the successfully generated outgoing edges verifying the predicate are
+/// kept along with the errors.
+impl<N, P> TryWeighted for FilterOutgoing<N, P>
+where
+    N: Copy + TryWeighted,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    type Weight = N::Weight;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        self.node
+            .try_weighted_outgoing()
+            .filter(move |edge| {
+                edge.as_ref()
+                    .map_or(true, |(_, next)| (self.pred)(&self.node, next))
+            })
+            .map(move |edge| edge.map(|(weight, node)| (weight, Self::new(node, self.pred))))
+    }
+}
+
+/// [`Incoming`] implementation for [`FilterOutgoing`]
+///
+/// Only the incoming nodes whose edge verifies the predicate are kept.
+impl<N, P> Incoming for FilterOutgoing<N, P>
+where
+    N: Copy + Incoming,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        self.node
+            .incoming()
+            .filter(move |previous| (self.pred)(previous, &self.node))
+            .map(move |node| Self::new(node, self.pred))
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`FilterOutgoing`]
+///
+/// Only the incoming edges verifying the predicate are kept.
+impl<N, P> WeightedIncoming for FilterOutgoing<N, P>
+where
+    N: Copy + WeightedIncoming,
+    P: Copy + Fn(&N, &N) -> bool,
+{
+    type Weight = N::Weight;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_incoming()
+            .filter(move |(_, previous)| (self.pred)(previous, &self.node))
+            .map(move |(weight, node)| (weight, Self::new(node, self.pred)))
+    }
+}
+
+/// A [node](Weighted) whose weights are mapped
+///
+/// This `struct` is created by [`.map_weight()`](Weighted::map_weight). See
+/// its documentation for more information.
+#[derive(Clone, Copy)]
+pub struct MapWeight<N, F> {
+    node: N,
+    f: F,
+}
+
+impl<N, F> MapWeight<N, F> {
+    /// Create a new [`MapWeight`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node whose weights are mapped
+    /// - `f` - The function mapping every weight
+    ///
+    /// # Return
+    ///
+    /// A new [`MapWeight`] node wrapping `node`.
+    pub fn new(node: N, f: F) -> Self {
+        Self { node, f }
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+impl<N: Debug, F> Debug for MapWeight<N, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapWeight")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`PartialEq`] implementation for [`MapWeight`]
+///
+/// The wrapped node is transparently compared with.
+impl<N: PartialEq, F> PartialEq for MapWeight<N, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.eq(&other.node)
+    }
+}
+
+/// [`Eq`] implementation for [`MapWeight`]
+///
+/// The wrapped node is transparently compared with.
+impl<N: Eq, F> Eq for MapWeight<N, F> {}
+
+/// [`Hash`] implementation for [`MapWeight`]
+///
+/// The wrapped node is transparently hashed.
+impl<N: Hash, F> Hash for MapWeight<N, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+    }
+}
+
+/// [`ToValue`] implementation for [`MapWeight`]
+///
+/// This implementation allows casting to the wrapped node.
+impl<N, F> ToValue<N> for MapWeight<N, F> {
+    fn to_value(self) -> N {
+        self.node
+    }
+}
+
+/// [`Node`] implementation for [`MapWeight`]
+///
+/// The outgoing nodes of the wrapped node are kept as is.
+impl<N, F> Node for MapWeight<N, F>
+where
+    N: Node,
+    F: Copy,
+{
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.node
+            .outgoing()
+            .map(move |node| Self::new(node, self.f))
+    }
+}
+
+/// [`Weighted`] implementation for [`MapWeight`]
+///
+/// The weights of the wrapped node are mapped.
+impl<N, F, V> Weighted for MapWeight<N, F>
+where
+    N: Weighted,
+    F: Copy + Fn(N::Weight) -> V,
+{
+    type Weight = V;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_outgoing()
+            .map(move |(weight, node)| ((self.f)(weight), Self::new(node, self.f)))
+    }
+}
+
+/// [`Labeled`] implementation for [`MapWeight`]
+///
+/// The labeled outgoing edges of the wrapped node are kept as is.
+impl<N, F> Labeled for MapWeight<N, F>
+where
+    N: Labeled,
+    F: Copy,
+{
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        self.node
+            .labeled_outgoing()
+            .map(move |(label, node)| (label, Self::new(node, self.f)))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`MapWeight`]
+///
+/// The weights of the wrapped node are mapped while the labels are kept as
+/// is.
+impl<N, F, V> WeightedLabeled for MapWeight<N, F>
+where
+    N: WeightedLabeled,
+    F: Copy + Fn(N::Weight) -> V,
+{
+    type Weight = V;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        self.node
+            .weighted_labeled_outgoing()
+            .map(move |(weight, label, node)| ((self.f)(weight), label, Self::new(node, self.f)))
+    }
+}
+
+/// [`TryNode`] implementation for [`MapWeight`]
+///
+/// The outgoing nodes and the errors of the wrapped node are kept as is.
+impl<N, F> TryNode for MapWeight<N, F>
+where
+    N: TryNode,
+    F: Copy,
+{
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        self.node
+            .try_outgoing()
+            .map(move |node| node.map(|node| Self::new(node, self.f)))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`MapWeight`]
+///
+/// The weights of the successfully generated edges of the wrapped node are
+/// mapped.
+impl<N, F, V> TryWeighted for MapWeight<N, F>
+where
+    N: TryWeighted,
+    F: Copy + Fn(N::Weight) -> V,
+{
+    type Weight = V;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        self.node
+            .try_weighted_outgoing()
+            .map(move |edge| edge.map(|(weight, node)| ((self.f)(weight), Self::new(node, self.f))))
+    }
+}
+
+/// [`Incoming`] implementation for [`MapWeight`]
+///
+/// The incoming nodes of the wrapped node are kept as is.
+impl<N, F> Incoming for MapWeight<N, F>
+where
+    N: Incoming,
+    F: Copy,
+{
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        self.node
+            .incoming()
+            .map(move |node| Self::new(node, self.f))
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`MapWeight`]
+///
+/// The weights of the wrapped node are mapped.
+impl<N, F, V> WeightedIncoming for MapWeight<N, F>
+where
+    N: WeightedIncoming,
+    F: Copy + Fn(N::Weight) -> V,
+{
+    type Weight = V;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.node
+            .weighted_incoming()
+            .map(move |(weight, node)| ((self.f)(weight), Self::new(node, self.f)))
+    }
+}
+
+/// A [node](Node) whose edges are weighted by `1`
+///
+/// This `struct` is created by
+/// [`.with_unit_weights()`](Node::with_unit_weights). See its documentation for
+/// more information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitWeights<N> {
+    node: N,
+}
+
+impl<N> UnitWeights<N> {
+    /// Create a new [`UnitWeights`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node whose edges are weighted by `1`
+    ///
+    /// # Return
+    ///
+    /// A new [`UnitWeights`] node wrapping `node`.
+    pub fn new(node: N) -> Self {
+        Self { node }
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+/// [`ToValue`] implementation for [`UnitWeights`]
+///
+/// This implementation allows casting to the wrapped node.
+impl<N> ToValue<N> for UnitWeights<N> {
+    fn to_value(self) -> N {
+        self.node
+    }
+}
+
+/// [`Node`] implementation for [`UnitWeights`]
+///
+/// The outgoing nodes of the wrapped node are kept as is.
+impl<N: Node> Node for UnitWeights<N> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.node.outgoing().map(Self::new)
+    }
+}
+
+/// [`Weighted`] implementation for [`UnitWeights`]
+///
+/// Every outgoing edge of the wrapped node is weighted by `1`.
+impl<N: Node> Weighted for UnitWeights<N> {
+    type Weight = usize;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.outgoing().map(|node| (1, node))
+    }
+}
+
+/// [`Labeled`] implementation for [`UnitWeights`]
+///
+/// The labeled outgoing edges of the wrapped node are kept as is.
+impl<N: Labeled> Labeled for UnitWeights<N> {
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        self.node
+            .labeled_outgoing()
+            .map(|(label, node)| (label, Self::new(node)))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`UnitWeights`]
+///
+/// Every labeled outgoing edge of the wrapped node is weighted by `1`.
+impl<N: Labeled> WeightedLabeled for UnitWeights<N> {
+    type Weight = usize;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        self.labeled_outgoing()
+            .map(|(label, node)| (1, label, node))
+    }
+}
+
+/// [`TryNode`] implementation for [`UnitWeights`]
+///
+/// The outgoing nodes and the errors of the wrapped node are kept as is.
+impl<N: TryNode> TryNode for UnitWeights<N> {
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        self.node.try_outgoing().map(|node| node.map(Self::new))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`UnitWeights`]
+///
+/// Every successfully generated outgoing edge of the wrapped node is weighted
+/// by `1`.
+impl<N: TryNode> TryWeighted for UnitWeights<N> {
+    type Weight = usize;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        self.try_outgoing().map(|node| node.map(|node| (1, node)))
+    }
+}
+
+/// [`Incoming`] implementation for [`UnitWeights`]
+///
+/// The incoming nodes of the wrapped node are kept as is.
+impl<N: Incoming> Incoming for UnitWeights<N> {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        self.node.incoming().map(Self::new)
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`UnitWeights`]
+///
+/// Every incoming edge of the wrapped node is weighted by `1`.
+impl<N: Incoming> WeightedIncoming for UnitWeights<N> {
+    type Weight = usize;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.incoming().map(|node| (1, node))
+    }
+}
+
+/// A [node](Node) carrying its depth
+///
+/// This `struct` is created by [`.with_depth()`](Node::with_depth). See its
+/// documentation for more information.
+///
+/// As the depth is part of the node identity, a node reached at several depths
+/// is visited once per depth. Hence, the graph of a [`WithDepth`] node is
+/// infinite as soon as a cycle is reachable and has to be bounded, e.g. using
+/// [`TakeDepth`], unless the search stops at a found node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WithDepth<N> {
+    node: N,
+    depth: usize,
+}
+
+impl<N> WithDepth<N> {
+    /// Create a new [`WithDepth`] node at depth `0`
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node
+    ///
+    /// # Return
+    ///
+    /// A new [`WithDepth`] node wrapping `node`.
+    pub fn new(node: N) -> Self {
+        Self { node, depth: 0 }
+    }
+
+    /// Get the depth of the current node
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+/// [`ToValue`] implementation for [`WithDepth`]
+///
+/// This implementation allows casting to the wrapped node and its depth.
+impl<N> ToValue<(N, usize)> for WithDepth<N> {
+    fn to_value(self) -> (N, usize) {
+        (self.node, self.depth)
+    }
+}
+
+/// [`Node`] implementation for [`WithDepth`]
+///
+/// The outgoing nodes are one level deeper than the current one.
+impl<N: Node> Node for WithDepth<N> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let depth = self.depth + 1;
+        self.node.outgoing().map(move |node| Self { node, depth })
+    }
+}
+
+/// [`Weighted`] implementation for [`WithDepth`]
+///
+/// The outgoing nodes are one level deeper than the current one.
+impl<N: Weighted> Weighted for WithDepth<N> {
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let depth = self.depth + 1;
+
+        self.node
+            .weighted_outgoing()
+            .map(move |(weight, node)| (weight, Self { node, depth }))
+    }
+}
+
+/// [`Labeled`] implementation for [`WithDepth`]
+///
+/// The outgoing nodes are one level deeper than the current one while the
+/// labels are kept as is.
+impl<N: Labeled> Labeled for WithDepth<N> {
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        let depth = self.depth + 1;
+
+        self.node
+            .labeled_outgoing()
+            .map(move |(label, node)| (label, Self { node, depth }))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`WithDepth`]
+///
+/// The outgoing nodes are one level deeper than the current one while the
+/// weights and the labels are kept as is.
+impl<N: WeightedLabeled> WeightedLabeled for WithDepth<N> {
+    type Weight = N::Weight;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        let depth = self.depth + 1;
+
+        self.node
+            .weighted_labeled_outgoing()
+            .map(move |(weight, label, node)| (weight, label, Self { node, depth }))
+    }
+}
+
+/// [`TryNode`] implementation for [`WithDepth`]
+///
+/// The successfully generated outgoing nodes are one level deeper than the
+/// current one.
+impl<N: TryNode> TryNode for WithDepth<N> {
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        let depth = self.depth + 1;
+
+        self.node
+            .try_outgoing()
+            .map(move |node| node.map(|node| Self { node, depth }))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`WithDepth`]
+///
+/// The successfully generated outgoing nodes are one level deeper than the
+/// current one while the weights are kept as is.
+impl<N: TryWeighted> TryWeighted for WithDepth<N> {
+    type Weight = N::Weight;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        let depth = self.depth + 1;
+
+        self.node
+            .try_weighted_outgoing()
+            .map(move |edge| edge.map(|(weight, node)| (weight, Self { node, depth })))
+    }
+}
+
+/// [`Incoming`] implementation for [`WithDepth`]
+///
+/// The incoming nodes are one level deeper than the current one as well, the
+/// depth being the number of edges followed from the root whatever their
+/// direction.
+impl<N: Incoming> Incoming for WithDepth<N> {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        let depth = self.depth + 1;
+        self.node.incoming().map(move |node| Self { node, depth })
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`WithDepth`]
+///
+/// The incoming nodes are one level deeper than the current one as well while
+/// the weights are kept as is.
+impl<N: WeightedIncoming> WeightedIncoming for WithDepth<N> {
+    type Weight = N::Weight;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let depth = self.depth + 1;
+
+        self.node
+            .weighted_incoming()
+            .map(move |(weight, node)| (weight, Self { node, depth }))
+    }
+}
+
+/// A [node](Node) whose graph is unfolded until a maximum depth
+///
+/// This `struct` is created by [`.take_depth()`](Node::take_depth). See its
+/// documentation for more information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TakeDepth<N> {
+    node: WithDepth<N>,
+    max_depth: usize,
+}
+
+impl<N> TakeDepth<N> {
+    /// Create a new [`TakeDepth`] node at depth `0`
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node
+    /// - `max_depth` - The depth of the nodes without outgoing node
+    ///
+    /// # Return
+    ///
+    /// A new [`TakeDepth`] node wrapping `node`.
+    pub fn new(node: N, max_depth: usize) -> Self {
+        Self {
+            node: WithDepth::new(node),
+            max_depth,
+        }
+    }
+
+    /// Get the depth of the current node
+    pub fn depth(&self) -> usize {
+        self.node.depth
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node.node
+    }
+
+    /// Get the items given by `next` unless the maximum depth is reached
+    fn unfold<I, F>(self, next: F) -> impl Iterator<Item = I::Item>
+    where
+        I: IntoIterator,
+        F: FnOnce(WithDepth<N>) -> I,
+    {
+        (self.node.depth < self.max_depth)
+            .then(|| next(self.node))
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// [`ToValue`] implementation for [`TakeDepth`]
+///
+/// This implementation allows casting to the wrapped node.
+impl<N> ToValue<N> for TakeDepth<N> {
+    fn to_value(self) -> N {
+        self.node.node
+    }
+}
+
+/// [`Node`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no outgoing node.
+impl<N: Node> Node for TakeDepth<N> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let max_depth = self.max_depth;
+
+        self.unfold(Node::outgoing)
+            .map(move |node| Self { node, max_depth })
+    }
+}
+
+/// [`Weighted`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no outgoing edge.
+impl<N: Weighted> Weighted for TakeDepth<N> {
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let max_depth = self.max_depth;
+
+        self.unfold(Weighted::weighted_outgoing)
+            .map(move |(weight, node)| (weight, Self { node, max_depth }))
+    }
+}
+
+/// [`Labeled`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no outgoing edge.
+impl<N: Labeled> Labeled for TakeDepth<N> {
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        let max_depth = self.max_depth;
+
+        self.unfold(Labeled::labeled_outgoing)
+            .map(move |(label, node)| (label, Self { node, max_depth }))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no outgoing edge.
+impl<N: WeightedLabeled> WeightedLabeled for TakeDepth<N> {
+    type Weight = N::Weight;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        let max_depth = self.max_depth;
+
+        self.unfold(WeightedLabeled::weighted_labeled_outgoing)
+            .map(move |(weight, label, node)| (weight, label, Self { node, max_depth }))
+    }
+}
+
+/// [`TryNode`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no outgoing node.
+impl<N: TryNode> TryNode for TakeDepth<N> {
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        let max_depth = self.max_depth;
+
+        self.unfold(TryNode::try_outgoing)
+            .map(move |node| node.map(|node| Self { node, max_depth }))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no outgoing edge.
+impl<N: TryWeighted> TryWeighted for TakeDepth<N> {
+    type Weight = N::Weight;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        let max_depth = self.max_depth;
+
+        self.unfold(TryWeighted::try_weighted_outgoing)
+            .map(move |edge| edge.map(|(weight, node)| (weight, Self { node, max_depth })))
+    }
+}
+
+/// [`Incoming`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no incoming node, the depth being the
+/// number of edges followed from the root whatever their direction.
+impl<N: Incoming> Incoming for TakeDepth<N> {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        let max_depth = self.max_depth;
+
+        self.unfold(Incoming::incoming)
+            .map(move |node| Self { node, max_depth })
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`TakeDepth`]
+///
+/// The nodes at the maximum depth have no incoming edge.
+impl<N: WeightedIncoming> WeightedIncoming for TakeDepth<N> {
+    type Weight = N::Weight;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let max_depth = self.max_depth;
+
+        self.unfold(WeightedIncoming::weighted_incoming)
+            .map(move |(weight, node)| (weight, Self { node, max_depth }))
+    }
+}
+
+/// A [node](Node) with a [`Heuristic`] given by a closure
+///
+/// This `struct` is created by [`.with_heuristic()`](Node::with_heuristic).
+/// See its documentation for more information.
+#[derive(Clone, Copy)]
+pub struct WithHeuristic<N, H> {
+    node: N,
+    heuristic: H,
+}
+
+impl<N, H> WithHeuristic<N, H> {
+    /// Create a new [`WithHeuristic`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The wrapped node
+    /// - `heuristic` - The closure estimating the distance of a node to the
+    ///   goal
+    ///
+    /// # Return
+    ///
+    /// A new [`WithHeuristic`] node wrapping `node`.
+    pub fn new(node: N, heuristic: H) -> Self {
+        Self { node, heuristic }
+    }
+
+    /// Get the wrapped node
+    pub fn into_inner(self) -> N {
+        self.node
+    }
+}
+
+impl<N: Debug, H> Debug for WithHeuristic<N, H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WithHeuristic")
+            .field("node", &self.node)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`PartialEq`] implementation for [`WithHeuristic`]
+///
+/// The wrapped node is transparently compared with.
+impl<N: PartialEq, H> PartialEq for WithHeuristic<N, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.eq(&other.node)
+    }
+}
+
+/// [`Eq`] implementation for [`WithHeuristic`]
+///
+/// The wrapped node is transparently compared with.
+impl<N: Eq, H> Eq for WithHeuristic<N, H> {}
+
+/// [`Hash`] implementation for [`WithHeuristic`]
+///
+/// The wrapped node is transparently hashed.
+impl<N: Hash, H> Hash for WithHeuristic<N, H> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.node.hash(state);
+    }
+}
+
+/// [`ToValue`] implementation for [`WithHeuristic`]
+///
+/// This implementation allows casting to the wrapped node.
+impl<N, H> ToValue<N> for WithHeuristic<N, H> {
+    fn to_value(self) -> N {
+        self.node
+    }
+}
+
+/// [`Heuristic`] implementation for [`WithHeuristic`]
+///
+/// The distance is estimated by the closure.
+impl<N, H, C> Heuristic for WithHeuristic<N, H>
+where
+    H: Fn(&N) -> C,
+{
+    type Cost = C;
+
+    fn heuristic(&self) -> Self::Cost {
+        (self.heuristic)(&self.node)
+    }
+}
+
+/// [`Node`] implementation for [`WithHeuristic`]
+///
+/// The outgoing nodes of the wrapped node are kept as is.
+impl<N: Node, H: Copy> Node for WithHeuristic<N, H> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let heuristic = self.heuristic;
+        self.node
+            .outgoing()
+            .map(move |node| Self::new(node, heuristic))
+    }
+}
+
+/// [`Weighted`] implementation for [`WithHeuristic`]
+///
+/// The outgoing edges of the wrapped node are kept as is.
+impl<N: Weighted, H: Copy> Weighted for WithHeuristic<N, H> {
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let heuristic = self.heuristic;
+
+        self.node
+            .weighted_outgoing()
+            .map(move |(weight, node)| (weight, Self::new(node, heuristic)))
+    }
+}
+
+/// [`Labeled`] implementation for [`WithHeuristic`]
+///
+/// The labeled outgoing edges of the wrapped node are kept as is.
+impl<N: Labeled, H: Copy> Labeled for WithHeuristic<N, H> {
+    type Label = N::Label;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        let heuristic = self.heuristic;
+
+        self.node
+            .labeled_outgoing()
+            .map(move |(label, node)| (label, Self::new(node, heuristic)))
+    }
+}
+
+/// [`WeightedLabeled`] implementation for [`WithHeuristic`]
+///
+/// The weighted and labeled outgoing edges of the wrapped node are kept as is.
+impl<N: WeightedLabeled, H: Copy> WeightedLabeled for WithHeuristic<N, H> {
+    type Weight = N::Weight;
+    type Label = N::Label;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        let heuristic = self.heuristic;
+
+        self.node
+            .weighted_labeled_outgoing()
+            .map(move |(weight, label, node)| (weight, label, Self::new(node, heuristic)))
+    }
+}
+
+/// [`TryNode`] implementation for [`WithHeuristic`]
+///
+/// The outgoing nodes and the errors of the wrapped node are kept as is.
+impl<N: TryNode, H: Copy> TryNode for WithHeuristic<N, H> {
+    type Error = N::Error;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        let heuristic = self.heuristic;
+
+        self.node
+            .try_outgoing()
+            .map(move |node| node.map(|node| Self::new(node, heuristic)))
+    }
+}
+
+/// [`TryWeighted`] implementation for [`WithHeuristic`]
+///
+/// The outgoing edges and the errors of the wrapped node are kept as is.
+impl<N: TryWeighted, H: Copy> TryWeighted for WithHeuristic<N, H> {
+    type Weight = N::Weight;
+    type Error = N::Error;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        let heuristic = self.heuristic;
+
+        self.node
+            .try_weighted_outgoing()
+            .map(move |edge| edge.map(|(weight, node)| (weight, Self::new(node, heuristic))))
+    }
+}
+
+/// [`Incoming`] implementation for [`WithHeuristic`]
+///
+/// The incoming nodes of the wrapped node are kept as is.
+impl<N: Incoming, H: Copy> Incoming for WithHeuristic<N, H> {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        let heuristic = self.heuristic;
+        self.node
+            .incoming()
+            .map(move |node| Self::new(node, heuristic))
+    }
+}
+
+/// [`WeightedIncoming`] implementation for [`WithHeuristic`]
+///
+/// The incoming edges of the wrapped node are kept as is.
+impl<N: WeightedIncoming, H: Copy> WeightedIncoming for WithHeuristic<N, H> {
+    type Weight = N::Weight;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let heuristic = self.heuristic;
+
+        self.node
+            .weighted_incoming()
+            .map(move |(weight, node)| (weight, Self::new(node, heuristic)))
+    }
+}
+
+/// A boxed [`DynNode`]
+///
+/// This wrapper implements [`Node`] as well as [`Clone`], [`Eq`] and [`Hash`]
+/// through the [`DynIdentity`](crate::DynIdentity) of the boxed node. It is
+/// explored through a [`DynInterner`]. See the documentation of [`DynNode`]
+/// for more information.
+pub struct BoxedNode {
+    node: Box<dyn DynNode>,
+}
+
+impl BoxedNode {
+    /// Create a new [`BoxedNode`]
+    ///
+    /// # Arguments
+    ///
+    /// - `node` - The boxed node
+    ///
+    /// # Return
+    ///
+    /// A new [`BoxedNode`] boxing `node`.
+    pub fn new<N: DynNode>(node: N) -> Self {
+        Self {
+            node: Box::new(node),
+        }
+    }
+
+    /// Get a reference to the boxed node if it is of type `N`
+    pub fn downcast_ref<N: Any>(&self) -> Option<&N> {
+        (&*self.node as &dyn Any).downcast_ref()
+    }
+
+    /// Get the boxed node
+    pub fn into_inner(self) -> Box<dyn DynNode> {
+        self.node
+    }
+}
+
+impl Clone for BoxedNode {
+    fn clone(&self) -> Self {
+        self.node.dyn_clone()
+    }
+}
+
+impl Debug for BoxedNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoxedNode").finish_non_exhaustive()
+    }
+}
+
+/// [`PartialEq`] implementation for [`BoxedNode`]
+///
+/// The boxed nodes are compared using [`.dyn_eq()`](crate::DynIdentity::dyn_eq).
+impl PartialEq for BoxedNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.dyn_eq(&*other.node)
+    }
+}
+
+impl Eq for BoxedNode {}
+
+/// [`Hash`] implementation for [`BoxedNode`]
+///
+/// The boxed node is hashed using [`.dyn_hash()`](crate::DynIdentity::dyn_hash).
+impl Hash for BoxedNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.dyn_hash(state);
+    }
+}
+
+/// [`Node`] implementation for [`BoxedNode`]
+///
+/// The boxed outgoing nodes of the boxed node are collected beforehand.
+impl Node for BoxedNode {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        Vec::from_iter(self.node.dyn_outgoing()).into_iter()
+    }
+}
+
+/// An interner giving [`Copy`] handles to [`BoxedNode`]s
+///
+/// As [`BoxedNode`] is not [`Copy`], it cannot be explored directly by the
+/// [`Process`es](crate::process::Process). A [`DynInterner`] stores every
+/// interned [`BoxedNode`] once and gives back a [`DynHandle`] which is
+/// [`Copy`] and implements [`Node`]. The outgoing nodes of a handle are
+/// interned on the fly so that a graph mixing different state types can be
+/// explored lazily, i.e. without being [materialized](Node::materialize)
+/// first. The interner only grows with the explored nodes and can be shared
+/// between threads.
+///
+/// See the documentation of [`DynNode`] for an example.
+#[derive(Default)]
+pub struct DynInterner {
+    interned: RwLock<Interned>,
+}
+
+/// The nodes stored by a [`DynInterner`]
+#[derive(Default)]
+struct Interned {
+    nodes: Vec<BoxedNode>,
+    indices: HashMap<BoxedNode, usize>,
+}
+
+impl DynInterner {
+    /// Create a new empty [`DynInterner`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern `node`
+    ///
+    /// # Return
+    ///
+    /// The handle of `node`, which is the same as before if an equal node has
+    /// already been interned.
+    pub fn intern(&self, node: BoxedNode) -> DynHandle<'_> {
+        let mut interned = self.write();
+        let next = interned.nodes.len();
+
+        let index = match interned.indices.entry(node) {
+            Entry::Occupied(entry) => *entry.get(),
+
+            Entry::Vacant(entry) => {
+                let node = entry.key().clone();
+                entry.insert(next);
+                interned.nodes.push(node);
+                next
+            }
+        };
+
+        DynHandle {
+            index,
+            interner: self,
+        }
+    }
+
+    /// Get the number of interned nodes
+    pub fn len(&self) -> usize {
+        self.read().nodes.len()
+    }
+
+    /// Check whether no node has been interned
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Lock the interned nodes for reading
+    fn read(&self) -> RwLockReadGuard<'_, Interned> {
+        self.interned.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lock the interned nodes for writing
+    fn write(&self) -> RwLockWriteGuard<'_, Interned> {
+        self.interned
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Debug for DynInterner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynInterner")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`Copy`] handle to a [`BoxedNode`] interned by a [`DynInterner`]
+///
+/// Two handles are equal if they have been given by the same interner for
+/// equal nodes.
+#[derive(Clone, Copy)]
+pub struct DynHandle<'a> {
+    index: usize,
+    interner: &'a DynInterner,
+}
+
+impl DynHandle<'_> {
+    /// Get the index of the interned node
+    ///
+    /// The nodes are indexed in their interning order.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get a clone of the interned node
+    pub fn to_boxed(&self) -> BoxedNode {
+        self.interner.read().nodes[self.index].clone()
+    }
+
+    /// Get a clone of the interned node if it is of type `N`
+    pub fn downcast<N: Any + Clone>(&self) -> Option<N> {
+        self.interner.read().nodes[self.index]
+            .downcast_ref()
+            .cloned()
+    }
+}
+
+impl Debug for DynHandle<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynHandle")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`PartialEq`] implementation for [`DynHandle`]
+///
+/// The indices and the interners are compared.
+impl PartialEq for DynHandle<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && std::ptr::eq(self.interner, other.interner)
+    }
+}
+
+impl Eq for DynHandle<'_> {}
+
+/// [`Hash`] implementation for [`DynHandle`]
+///
+/// Only the index is used.
+impl Hash for DynHandle<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+/// [`Node`] implementation for [`DynHandle`]
+///
+/// The boxed outgoing nodes of the interned node are collected beforehand and
+/// then interned.
+impl Node for DynHandle<'_> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let outgoing = Vec::from_iter(self.interner.read().nodes[self.index].node.dyn_outgoing());
+        outgoing
+            .into_iter()
+            .map(move |node| self.interner.intern(node))
+    }
+}
@@ -0,0 +1,74 @@
+//! This module contains the implementation of [`Counted`]
+
+use nodify::prelude::*;
+
+use std::{
+    hash::{Hash, Hasher},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A [node](Node) counting the expansions of the explored nodes
+#[derive(Clone, Copy, Debug)]
+pub struct Counted<'a, N> {
+    node: N,
+    expanded: &'a AtomicUsize,
+}
+
+impl<'a, N> Counted<'a, N> {
+    /// Create a new [`Counted`] node incrementing `expanded` at every expansion
+    pub fn new(node: N, expanded: &'a AtomicUsize) -> Self {
+        Self { node, expanded }
+    }
+}
+
+impl<W> Counted<'_, NodeRef<'_, W>> {
+    /// Get the index of the wrapped node
+    pub fn index(&self) -> usize {
+        self.node.index()
+    }
+}
+
+/// [`PartialEq`] trait implementation
+///
+/// Only the wrapped node is used.
+impl<N: PartialEq> PartialEq for Counted<'_, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+impl<N: Eq> Eq for Counted<'_, N> {}
+
+/// [`Hash`] trait implementation
+///
+/// Only the wrapped node is used.
+impl<N: Hash> Hash for Counted<'_, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+    }
+}
+
+impl<N: Node> Node for Counted<'_, N> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.expanded.fetch_add(1, Ordering::Relaxed);
+
+        let expanded = self.expanded;
+        self.node
+            .outgoing()
+            .map(move |node| Self { node, expanded })
+    }
+}
+
+impl<N: Weighted> Weighted for Counted<'_, N> {
+    type Weight = N::Weight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.expanded.fetch_add(1, Ordering::Relaxed);
+
+        let expanded = self.expanded;
+
+        self.node
+            .weighted_outgoing()
+            .map(move |(weight, node)| (weight, Self { node, expanded }))
+    }
+}
@@ -0,0 +1,281 @@
+//! This module contains the loading of the graph files
+
+use clap::ValueEnum;
+use nodify::prelude::*;
+
+use std::{collections::HashMap, error::Error, io::Read, iter::Peekable, path::Path, str::Chars};
+
+/// The format of a graph file
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// An edge list whose optional third column is the weight
+    EdgeList,
+
+    /// The DIMACS shortest path `.gr` format
+    Dimacs,
+
+    /// The Graphviz DOT format whose `weight` or `label` edge attribute is
+    /// the weight and whose undirected edges go both ways
+    Dot,
+}
+
+impl Format {
+    /// Guess the format of `path` from its extension
+    ///
+    /// The edge list format is used by default.
+    pub fn guess(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gr") => Self::Dimacs,
+            Some("dot" | "gv") => Self::Dot,
+            _ => Self::EdgeList,
+        }
+    }
+}
+
+/// A loaded graph
+pub struct Loaded {
+    /// The graph whose missing weights are `1`
+    pub graph: Graph<u64>,
+
+    /// The identifier of every node in the graph file
+    pub names: Vec<String>,
+}
+
+impl Loaded {
+    /// Read a graph file of format `format`
+    pub fn read<I: Read>(mut input: I, format: Format) -> Result<Self, Box<dyn Error>> {
+        let mut content = String::default();
+        input.read_to_string(&mut content)?;
+
+        let loaded = match format {
+            Format::EdgeList => {
+                let is_weighted = content
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty() && !line.starts_with(['#', '%']))
+                    .is_some_and(|line| line.split_whitespace().count() > 2);
+
+                let graph = if is_weighted {
+                    Graph::read_weighted_edge_list(content.as_bytes())?
+                } else {
+                    Graph::read_edge_list(content.as_bytes())?.map_weights(|()| 1)
+                };
+
+                let names = (0..graph.node_count()).map(|index| index.to_string());
+                let names = names.collect();
+
+                Self { graph, names }
+            }
+
+            Format::Dimacs => {
+                let graph = Graph::read_dimacs(content.as_bytes())?;
+                let names = (1..=graph.node_count()).map(|id| id.to_string());
+                let names = names.collect();
+
+                Self { graph, names }
+            }
+
+            Format::Dot => read_dot(&content)?,
+        };
+
+        Ok(loaded)
+    }
+}
+
+/// A token of the DOT format
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// An identifier, a number or a quoted string
+    Id(String),
+
+    /// One of `{`, `}`, `[`, `]`, `=`, `,`, `;`, `->` and `--`
+    Symbol(&'static str),
+}
+
+/// Read a graph using the DOT format
+///
+/// Only the node and the edge statements are supported. Every edge of an
+/// undirected graph is added in both directions.
+fn read_dot(content: &str) -> Result<Loaded, Box<dyn Error>> {
+    let mut tokens = tokenize(content)?.into_iter().peekable();
+    let mut builder = GraphBuilder::new();
+    let mut names = Vec::default();
+    let mut indices = HashMap::new();
+
+    let mut index_of = |name: String| {
+        *indices.entry(name.clone()).or_insert_with(|| {
+            names.push(name);
+            builder.add_node()
+        })
+    };
+
+    let mut edges = Vec::default();
+
+    let mut is_directed = None;
+
+    loop {
+        match tokens.next() {
+            Some(Token::Symbol("{")) => break,
+            Some(Token::Id(id)) if id.eq_ignore_ascii_case("digraph") => is_directed = Some(true),
+            Some(Token::Id(id)) if id.eq_ignore_ascii_case("graph") => is_directed = Some(false),
+            Some(_) => (),
+            None => return Err("Missing graph body".into()),
+        }
+    }
+
+    let is_directed = is_directed.ok_or("Missing `graph` or `digraph` keyword")?;
+    let edge_op = Token::Symbol(if is_directed { "->" } else { "--" });
+
+    loop {
+        let name = match tokens.next() {
+            Some(Token::Id(name)) => name,
+            Some(Token::Symbol(";" | ",")) => continue,
+            Some(Token::Symbol("}")) => break,
+            Some(token) => return Err(format!("Unexpected token `{token:?}`").into()),
+            None => return Err("Missing closing brace".into()),
+        };
+
+        if tokens.next_if_eq(&Token::Symbol("=")).is_some() {
+            tokens.next();
+            continue;
+        }
+
+        let mut chain = vec![name];
+
+        while tokens.next_if_eq(&edge_op).is_some() {
+            match tokens.next() {
+                Some(Token::Id(name)) => chain.push(name),
+                _ => return Err("Missing edge target".into()),
+            }
+        }
+
+        let attributes = read_attributes(&mut tokens)?;
+
+        if chain.len() == 1 {
+            if !["graph", "node", "edge"].contains(&chain[0].as_str()) {
+                index_of(chain.remove(0));
+            }
+
+            continue;
+        }
+
+        let weight = match ["weight", "label"].map(|key| attributes.get(key)) {
+            [Some(value), _] | [None, Some(value)] => value
+                .parse()
+                .map_err(|_| format!("Invalid weight `{value}`"))?,
+            [None, None] => 1,
+        };
+
+        let chain = Vec::from_iter(chain.into_iter().map(&mut index_of));
+
+        for pair in chain.windows(2) {
+            edges.push((pair[0], pair[1], weight));
+
+            if !is_directed {
+                edges.push((pair[1], pair[0], weight));
+            }
+        }
+    }
+
+    for (from, to, weight) in edges {
+        builder.add_weighted_edge(from, to, weight);
+    }
+
+    Ok(Loaded {
+        graph: builder.build(),
+        names,
+    })
+}
+
+/// Read the optional attribute list of a DOT statement
+fn read_attributes<I>(tokens: &mut Peekable<I>) -> Result<HashMap<String, String>, Box<dyn Error>>
+where
+    I: Iterator<Item = Token>,
+{
+    let mut attributes = HashMap::new();
+
+    while tokens.next_if_eq(&Token::Symbol("[")).is_some() {
+        loop {
+            match tokens.next() {
+                Some(Token::Symbol("]")) => break,
+                Some(Token::Symbol(";" | ",")) => (),
+
+                Some(Token::Id(key)) => {
+                    if tokens.next_if_eq(&Token::Symbol("=")).is_some() {
+                        match tokens.next() {
+                            Some(Token::Id(value)) => attributes.insert(key, value),
+                            _ => return Err(format!("Missing value of `{key}`").into()),
+                        };
+                    }
+                }
+
+                _ => return Err("Unterminated attribute list".into()),
+            }
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Split `content` into DOT tokens
+fn tokenize(content: &str) -> Result<Vec<Token>, Box<dyn Error>> {
+    let mut chars = content.chars().peekable();
+    let mut tokens = Vec::default();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '{' => Token::Symbol("{"),
+            '}' => Token::Symbol("}"),
+            '[' => Token::Symbol("["),
+            ']' => Token::Symbol("]"),
+            '=' => Token::Symbol("="),
+            ',' => Token::Symbol(","),
+            ';' => Token::Symbol(";"),
+            '"' => Token::Id(read_quoted(&mut chars)?),
+
+            '-' if chars.next_if_eq(&'>').is_some() => Token::Symbol("->"),
+            '-' if chars.next_if_eq(&'-').is_some() => Token::Symbol("--"),
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                chars.by_ref().take_while(|&c| c != '\n').for_each(drop);
+                continue;
+            }
+
+            '#' => {
+                chars.by_ref().take_while(|&c| c != '\n').for_each(drop);
+                continue;
+            }
+
+            c if c.is_whitespace() => continue,
+
+            c if c.is_alphanumeric() || "_.-".contains(c) => {
+                let mut id = String::from(c);
+
+                while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || "_.".contains(c)) {
+                    id.push(c);
+                }
+
+                Token::Id(id)
+            }
+
+            c => return Err(format!("Unexpected character `{c}`").into()),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Read the rest of a quoted string
+fn read_quoted(chars: &mut Peekable<Chars<'_>>) -> Result<String, Box<dyn Error>> {
+    let mut string = String::default();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(string),
+            Some('\\') => string.extend(chars.next()),
+            Some(c) => string.push(c),
+            None => return Err("Unterminated quoted string".into()),
+        }
+    }
+}
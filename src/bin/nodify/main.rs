@@ -0,0 +1,140 @@
+//! Search a path between two nodes of a graph file
+//!
+//! The graph is read from an edge list, a DIMACS `.gr` file or a DOT file and
+//! explored using one of the nodify processes.
+
+mod counted;
+mod load;
+
+use clap::{Parser, ValueEnum};
+use counted::Counted;
+use load::{Format, Loaded};
+use nodify::prelude::*;
+
+use std::{
+    error::Error,
+    fs::File,
+    io::BufReader,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+/// Search a path between two nodes of a graph file using nodify
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    /// The graph file
+    file: PathBuf,
+
+    /// The identifier of the source node
+    #[arg(short, long)]
+    source: String,
+
+    /// The identifier of the target node
+    #[arg(short, long)]
+    target: String,
+
+    /// The process used to search the target node
+    #[arg(short, long, value_enum, default_value_t = Search::Dfs)]
+    process: Search,
+
+    /// The value of delta used by `delta-stepping` (chosen automatically by
+    /// default)
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    delta: Option<u64>,
+
+    /// The format of the graph file (guessed from its extension by default)
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+}
+
+/// The process used to search the target node
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Search {
+    /// The sequential DFS
+    Dfs,
+
+    /// The parallel DFS
+    ParallelDfs,
+
+    /// The delta stepping algorithm finding a shortest path
+    DeltaStepping,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let format = args.format.unwrap_or_else(|| Format::guess(&args.file));
+
+    let input = BufReader::new(File::open(&args.file)?);
+    let Loaded { graph, names } = Loaded::read(input, format)?;
+
+    let source = index_of(&names, &args.source)?;
+    let target = index_of(&names, &args.target)?;
+
+    let expanded = AtomicUsize::new(0);
+    let root = Counted::new(graph.node(source), &expanded);
+    let is_target = move |node: Counted<'_, NodeRef<'_, u64>>| node.index() == target;
+
+    let start = Instant::now();
+
+    let path = match args.process {
+        Search::Dfs => root.to_process::<DFS<_>>().find_path(is_target),
+        Search::ParallelDfs => root.to_process::<ParallelDFS<_>>().find_path(is_target),
+
+        Search::DeltaStepping => {
+            let process = DeltaStepping::from_node(root);
+
+            match args.delta {
+                Some(delta) => process.with_delta(delta).find_path(is_target),
+                None => process.find_path(is_target),
+            }
+        }
+    };
+
+    let elapsed = start.elapsed();
+
+    println!("reachable: {}", path.is_some());
+
+    if let Some(path) = path {
+        let indices = Vec::from_iter(path.iter().map(|node| node.index()));
+
+        let length = indices
+            .windows(2)
+            .map(|pair| {
+                graph
+                    .edges(pair[0])
+                    .filter(|&(_, to)| to == pair[1])
+                    .map(|(&weight, _)| weight)
+                    .min()
+                    .unwrap_or_default()
+            })
+            .sum::<u64>();
+
+        let path = Vec::from_iter(indices.iter().map(|&index| names[index].as_str()));
+
+        if args.process == Search::DeltaStepping {
+            println!("distance: {length}");
+        } else {
+            println!("path weight: {length}");
+        }
+
+        println!("path: {}", path.join(" -> "));
+        println!("hops: {}", path.len() - 1);
+    }
+
+    println!("nodes: {}", graph.node_count());
+    println!("edges: {}", graph.edge_count());
+    println!("expanded: {}", expanded.load(Ordering::Relaxed));
+    println!("elapsed: {elapsed:?}");
+
+    Ok(())
+}
+
+/// Get the index of the node identified by `name`
+fn index_of(names: &[String], name: &str) -> Result<usize, Box<dyn Error>> {
+    names
+        .iter()
+        .position(|candidate| candidate == name)
+        .ok_or_else(|| format!("Unknown node `{name}`").into())
+}
@@ -0,0 +1,285 @@
+//! The export of explored graphs
+//!
+//! This module contains [`Exporter`] which explores the graph of a
+//! [node](Node) and writes it using one of the following formats:
+//! - [DOT](https://graphviz.org/doc/info/lang.html) using
+//!   [`.write_dot()`](Exporter::write_dot) and
+//!   [`.write_weighted_dot()`](Exporter::write_weighted_dot).
+//!
+//! When the `serde` feature is enabled, this module also contains `NodeLink`
+//! which writes and reads graphs, paths and distance maps using the JSON
+//! node-link format and [GraphML](http://graphml.graphdrawing.org/).
+
+mod dot;
+
+#[cfg(feature = "serde")]
+mod node_link;
+
+#[cfg(feature = "serde")]
+pub use node_link::{NodeLink, NodeLinkEdge, NodeLinkNode};
+
+use super::{Node, Weighted};
+
+use std::{collections::hash_map::Entry, fmt::Debug, hash::Hash};
+
+type HashMap<K, V> = std::collections::HashMap<K, V, ahash::RandomState>;
+type HashSet<K> = std::collections::HashSet<K, ahash::RandomState>;
+
+/// The default node formatter of [`Exporter`]
+type DebugFormatter<N> = fn(&N) -> String;
+
+/// An exporter of the graph explored from a root [node](Node)
+///
+/// The graph is explored in breadth-first order. The exploration can be
+/// bounded using [`.with_max_nodes()`](Exporter::with_max_nodes) and
+/// [`.with_max_depth()`](Exporter::with_max_depth).
+///
+/// The nodes are labeled using their [`Debug`] implementation or the
+/// formatter given to [`.with_formatter()`](Exporter::with_formatter) or
+/// [`Exporter::with_formatter_for()`]. A path
+/// (e.g. one found by [`FindPath`](super::process::FindPath)) can be
+/// highlighted using [`.with_path()`](Exporter::with_path).
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Number(u32);
+///
+/// impl Node for Number {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         [Number((self.0 + 1) % 10), Number(self.0 * 2 % 10)].into_iter()
+///     }
+/// }
+///
+/// let path = Number(1)
+///     .to_process::<DFS<_>>()
+///     .find_path(|Number(n)| n == 2)
+///     .unwrap();
+///
+/// let mut dot = Vec::new();
+///
+/// Exporter::new(Number(1))
+///     .with_max_depth(2)
+///     .with_formatter(|Number(n): &Number| n.to_string())
+///     .with_path(path)
+///     .write_dot(&mut dot)
+///     .unwrap();
+///
+/// let dot = String::from_utf8(dot).unwrap();
+///
+/// assert!(dot.starts_with("digraph {"));
+/// assert!(dot.contains("0 [label=\"1\", color=red];"));
+/// assert!(dot.contains("1 [label=\"2\", color=red];"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Exporter<N, F> {
+    root: N,
+    max_nodes: Option<usize>,
+    max_depth: Option<usize>,
+    formatter: F,
+    path: Vec<N>,
+}
+
+impl<N: Debug> Exporter<N, DebugFormatter<N>> {
+    /// Create a new [`Exporter`] exploring from `root`
+    ///
+    /// The exploration is not bounded and the nodes are labeled using their
+    /// [`Debug`] implementation.
+    pub fn new(root: N) -> Self {
+        Self {
+            root,
+            max_nodes: None,
+            max_depth: None,
+            formatter: |node| format!("{node:?}"),
+            path: Vec::default(),
+        }
+    }
+}
+
+impl<N, F> Exporter<N, F>
+where
+    F: Fn(&N) -> String,
+{
+    /// Create a new [`Exporter`] exploring from `root` whose nodes are labeled
+    /// using `formatter`
+    ///
+    /// The exploration is not bounded. Unlike [`Exporter::new()`], the nodes
+    /// do not need to implement [`Debug`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         std::iter::once(Number((self.0 + 1) % 3))
+    ///     }
+    /// }
+    ///
+    /// let mut dot = Vec::new();
+    ///
+    /// Exporter::with_formatter_for(Number(0), |Number(n): &Number| format!("n{n}"))
+    ///     .write_dot(&mut dot)
+    ///     .unwrap();
+    ///
+    /// let dot = String::from_utf8(dot).unwrap();
+    ///
+    /// assert!(dot.contains("2 [label=\"n2\"];"));
+    /// ```
+    pub fn with_formatter_for(root: N, formatter: F) -> Self {
+        Self {
+            root,
+            max_nodes: None,
+            max_depth: None,
+            formatter,
+            path: Vec::default(),
+        }
+    }
+}
+
+impl<N, F> Exporter<N, F> {
+    /// Consumme the current [`Exporter`] instance and create another with the
+    /// same values as before except for the maximum number of explored nodes
+    /// which is updated to `max_nodes`.
+    pub fn with_max_nodes(self, max_nodes: usize) -> Self {
+        Self {
+            max_nodes: Some(max_nodes),
+            ..self
+        }
+    }
+
+    /// Consumme the current [`Exporter`] instance and create another with the
+    /// same values as before except for the maximum depth of the explored
+    /// nodes which is updated to `max_depth`.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// Consumme the current [`Exporter`] instance and create another with the
+    /// same values as before except for the node formatter which is updated to
+    /// `formatter`.
+    pub fn with_formatter<G>(self, formatter: G) -> Exporter<N, G>
+    where
+        G: Fn(&N) -> String,
+    {
+        Exporter {
+            root: self.root,
+            max_nodes: self.max_nodes,
+            max_depth: self.max_depth,
+            formatter,
+            path: self.path,
+        }
+    }
+
+    /// Consumme the current [`Exporter`] instance and create another with the
+    /// same values as before except for the highlighted path which is updated
+    /// to `path`.
+    pub fn with_path(self, path: impl IntoIterator<Item = N>) -> Self {
+        Self {
+            path: Vec::from_iter(path),
+            ..self
+        }
+    }
+}
+
+/// A graph explored by an [`Exporter`]
+struct Explored<N, E> {
+    /// The explored nodes in breadth-first order
+    nodes: Vec<N>,
+
+    /// The explored edges as triples of source index, target index and label
+    edges: Vec<(usize, usize, E)>,
+
+    /// The indices of the highlighted nodes
+    highlighted_nodes: HashSet<usize>,
+
+    /// The highlighted edges
+    highlighted_edges: HashSet<(usize, usize)>,
+}
+
+impl<N, F> Exporter<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(&N) -> String,
+{
+    /// Explore the graph of an unweighted [node](Node)
+    fn explore(&self) -> Explored<N, ()>
+    where
+        N: Node,
+    {
+        self.explore_with(|node| node.outgoing().map(|next| ((), next)))
+    }
+
+    /// Explore the graph of a [weighted node](Weighted)
+    fn explore_weighted(&self) -> Explored<N, N::Weight>
+    where
+        N: Weighted,
+    {
+        self.explore_with(N::weighted_outgoing)
+    }
+
+    /// Explore the graph using `outgoing` to get the labeled outgoing edges
+    fn explore_with<E, X, R>(&self, outgoing: X) -> Explored<N, E>
+    where
+        X: Fn(N) -> R,
+        R: Iterator<Item = (E, N)>,
+    {
+        let max_nodes = self.max_nodes.unwrap_or(usize::MAX);
+        let max_depth = self.max_depth.unwrap_or(usize::MAX);
+
+        let mut nodes = vec![self.root];
+        let mut depths = vec![0];
+        let mut indices = HashMap::from_iter([(self.root, 0)]);
+        let mut edges = Vec::default();
+        let mut current = 0;
+
+        while let Some(&node) = nodes.get(current) {
+            if depths[current] < max_depth {
+                for (label, next) in outgoing(node) {
+                    let index = match indices.entry(next) {
+                        Entry::Occupied(entry) => Some(*entry.get()),
+
+                        Entry::Vacant(entry) if nodes.len() < max_nodes => {
+                            nodes.push(next);
+                            depths.push(depths[current] + 1);
+                            Some(*entry.insert(nodes.len() - 1))
+                        }
+
+                        Entry::Vacant(_) => None,
+                    };
+
+                    if let Some(index) = index {
+                        edges.push((current, index, label));
+                    }
+                }
+            }
+
+            current += 1;
+        }
+
+        let path = Vec::from_iter(self.path.iter().map(|node| indices.get(node).copied()));
+        let highlighted_nodes = HashSet::from_iter(path.iter().flatten().copied());
+
+        let highlighted_edges = HashSet::from_iter(
+            path.windows(2)
+                .filter_map(|pair| Some((pair[0]?, pair[1]?))),
+        );
+
+        Explored {
+            nodes,
+            edges,
+            highlighted_nodes,
+            highlighted_edges,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! This module contains the [DOT](https://graphviz.org/doc/info/lang.html)
+//! export of [`Exporter`]
+
+use super::{Explored, Exporter};
+use crate::{Node, Weighted};
+
+use std::{
+    fmt::Display,
+    hash::Hash,
+    io::{self, Write},
+};
+
+impl<N, F> Exporter<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(&N) -> String,
+{
+    /// Explore the graph and write it using the DOT format
+    ///
+    /// # Arguments
+    ///
+    /// - `out` - The writer receiving the DOT graph
+    ///
+    /// # Return
+    ///
+    /// The error returned by `out` if any.
+    pub fn write_dot<O: Write>(&self, out: O) -> io::Result<()>
+    where
+        N: Node,
+    {
+        self.write_dot_with(out, self.explore(), |_| None)
+    }
+
+    /// Explore the graph and write it using the DOT format
+    ///
+    /// Contrary to [`.write_dot()`](Exporter::write_dot), the edges are
+    /// labeled with their weight.
+    ///
+    /// # Arguments
+    ///
+    /// - `out` - The writer receiving the DOT graph
+    ///
+    /// # Return
+    ///
+    /// The error returned by `out` if any.
+    pub fn write_weighted_dot<O: Write>(&self, out: O) -> io::Result<()>
+    where
+        N: Weighted,
+        N::Weight: Display,
+    {
+        let explored = self.explore_weighted();
+        self.write_dot_with(out, explored, |weight| Some(weight.to_string()))
+    }
+
+    /// Write `explored` using the DOT format
+    ///
+    /// `edge_label` is used to get the label of every edge.
+    fn write_dot_with<E, O, L>(
+        &self,
+        mut out: O,
+        explored: Explored<N, E>,
+        edge_label: L,
+    ) -> io::Result<()>
+    where
+        O: Write,
+        L: Fn(&E) -> Option<String>,
+    {
+        writeln!(out, "digraph {{")?;
+
+        for (index, node) in explored.nodes.iter().enumerate() {
+            let label = escape(&(self.formatter)(node));
+            write!(out, "    {index} [label=\"{label}\"")?;
+
+            if explored.highlighted_nodes.contains(&index) {
+                write!(out, ", color=red")?;
+            }
+
+            writeln!(out, "];")?;
+        }
+
+        for (from, to, label) in &explored.edges {
+            let mut attributes = Vec::default();
+
+            if let Some(label) = edge_label(label) {
+                attributes.push(format!("label=\"{}\"", escape(&label)));
+            }
+
+            if explored.highlighted_edges.contains(&(*from, *to)) {
+                attributes.push("color=red".to_owned());
+                attributes.push("penwidth=2".to_owned());
+            }
+
+            if attributes.is_empty() {
+                writeln!(out, "    {from} -> {to};")?;
+            } else {
+                writeln!(out, "    {from} -> {to} [{}];", attributes.join(", "))?;
+            }
+        }
+
+        writeln!(out, "}}")
+    }
+}
+
+/// Escape `label` in order to be used in a DOT quoted string
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,546 @@
+//! This module contains the implementation of [`NodeLink`]
+
+use super::HashSet;
+use crate::graph::Materialized;
+
+use quick_xml::{
+    Reader, XmlVersion,
+    escape::{escape, unescape},
+    events::{BytesStart, Event},
+};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::io::{self, BufRead, Write};
+
+/// A graph using the node-link format
+///
+/// This format is the one used by
+/// [NetworkX](https://networkx.org/documentation/stable/reference/readwrite/json_graph.html)
+/// to represent graphs as JSON. A [`NodeLink`] graph can also be written using
+/// [GraphML](http://graphml.graphdrawing.org/) where the node values, the
+/// distances and the weights are encoded as JSON strings.
+///
+/// A [`NodeLink`] graph is created from
+/// - a [`Materialized`] graph using
+///   [`.from_materialized()`](NodeLink::from_materialized),
+/// - a path (e.g. one found by [`FindPath`](crate::process::FindPath)) using
+///   [`.from_path()`](NodeLink::from_path), or
+/// - a distance map (e.g. the one of `DeltaStepping`) using
+///   [`.from_distances()`](NodeLink::from_distances).
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Number(u32);
+///
+/// impl Weighted for Number {
+///     type Weight = u32;
+///
+///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+///         [(1, Number((self.0 + 1) % 5)), (3, Number(self.0 * 2 % 5))].into_iter()
+///     }
+/// }
+///
+/// let materialized = Number(1).materialize_weighted();
+/// let graph = NodeLink::from_materialized(&materialized).map_nodes(|Number(n)| n);
+///
+/// let mut json = Vec::new();
+/// graph.write_json(&mut json).unwrap();
+/// assert_eq!(NodeLink::read_json(json.as_slice()).unwrap(), graph);
+///
+/// let mut graphml = Vec::new();
+/// graph.write_graphml(&mut graphml).unwrap();
+/// assert_eq!(NodeLink::read_graphml(graphml.as_slice()).unwrap(), graph);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NodeLink<N, W = ()> {
+    /// Whether the graph is directed
+    pub directed: bool,
+
+    /// Whether the graph may have several edges between two nodes
+    pub multigraph: bool,
+
+    /// The nodes of the graph
+    pub nodes: Vec<NodeLinkNode<N, W>>,
+
+    /// The edges of the graph
+    pub links: Vec<NodeLinkEdge<W>>,
+}
+
+/// A node of a [`NodeLink`] graph
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NodeLinkNode<N, W> {
+    /// The identifier of the node
+    pub id: usize,
+
+    /// The value of the node
+    pub value: N,
+
+    /// The distance of the node from the start node if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<W>,
+}
+
+/// An edge of a [`NodeLink`] graph
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NodeLinkEdge<W> {
+    /// The identifier of the source node
+    pub source: usize,
+
+    /// The identifier of the target node
+    pub target: usize,
+
+    /// The weight of the edge
+    pub weight: W,
+}
+
+impl<N, W> NodeLink<N, W> {
+    /// Create a new [`NodeLink`] graph from a [`Materialized`] graph
+    ///
+    /// The node identifiers are the indices of the [`Materialized`] graph.
+    pub fn from_materialized(materialized: &Materialized<N, W>) -> Self
+    where
+        N: Clone,
+        W: Clone,
+    {
+        let nodes = materialized
+            .nodes()
+            .iter()
+            .enumerate()
+            .map(|(id, value)| NodeLinkNode {
+                id,
+                value: value.clone(),
+                distance: None,
+            })
+            .collect();
+
+        let graph = materialized.graph();
+
+        let links = Vec::from_iter((0..graph.node_count()).flat_map(|source| {
+            graph
+                .edges(source)
+                .map(move |(weight, target)| NodeLinkEdge {
+                    source,
+                    target,
+                    weight: weight.clone(),
+                })
+        }));
+
+        Self {
+            directed: true,
+            multigraph: has_parallel_links(&links),
+            nodes,
+            links,
+        }
+    }
+
+    /// Create a new [`NodeLink`] graph from a distance map
+    ///
+    /// The graph has no edge and every node has a distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let graph = NodeLink::from_distances([("start", 0), ("middle", 2), ("goal", 5)]);
+    ///
+    /// let mut json = Vec::new();
+    /// graph.write_json(&mut json).unwrap();
+    ///
+    /// let read = NodeLink::<String, u32>::read_json(json.as_slice()).unwrap();
+    /// assert_eq!(read.map_nodes(|value| value.len()), graph.map_nodes(str::len));
+    /// ```
+    pub fn from_distances(distances: impl IntoIterator<Item = (N, W)>) -> Self {
+        let nodes = distances
+            .into_iter()
+            .enumerate()
+            .map(|(id, (value, distance))| NodeLinkNode {
+                id,
+                value,
+                distance: Some(distance),
+            })
+            .collect();
+
+        Self {
+            directed: true,
+            multigraph: false,
+            nodes,
+            links: Vec::default(),
+        }
+    }
+
+    /// Map the value of every node using `f`
+    pub fn map_nodes<M, F>(self, mut f: F) -> NodeLink<M, W>
+    where
+        F: FnMut(N) -> M,
+    {
+        let nodes = self
+            .nodes
+            .into_iter()
+            .map(|node| NodeLinkNode {
+                id: node.id,
+                value: f(node.value),
+                distance: node.distance,
+            })
+            .collect();
+
+        NodeLink {
+            directed: self.directed,
+            multigraph: self.multigraph,
+            nodes,
+            links: self.links,
+        }
+    }
+}
+
+impl<N> NodeLink<N> {
+    /// Create a new [`NodeLink`] graph from a path
+    ///
+    /// The nodes are identified by their position in the path and every node
+    /// is linked to the next one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let path = ["<start>", "\"middle\" & co", "goal"].map(String::from);
+    /// let graph = NodeLink::from_path(path);
+    ///
+    /// let mut graphml = Vec::new();
+    /// graph.write_graphml(&mut graphml).unwrap();
+    ///
+    /// assert_eq!(NodeLink::read_graphml(graphml.as_slice()).unwrap(), graph);
+    /// assert_eq!(graph.links.len(), 2);
+    /// ```
+    pub fn from_path(path: impl IntoIterator<Item = N>) -> Self {
+        let nodes = Vec::from_iter(
+            path.into_iter()
+                .enumerate()
+                .map(|(id, value)| NodeLinkNode {
+                    id,
+                    value,
+                    distance: None,
+                }),
+        );
+
+        let links = (1..nodes.len())
+            .map(|target| NodeLinkEdge {
+                source: target - 1,
+                target,
+                weight: (),
+            })
+            .collect();
+
+        Self {
+            directed: true,
+            multigraph: false,
+            nodes,
+            links,
+        }
+    }
+}
+
+impl<N, W> NodeLink<N, W>
+where
+    N: Serialize,
+    W: Serialize,
+{
+    /// Write the current graph using the JSON node-link format
+    ///
+    /// # Arguments
+    ///
+    /// - `out` - The writer receiving the JSON graph
+    ///
+    /// # Return
+    ///
+    /// The error raised while serializing if any.
+    pub fn write_json<O: Write>(&self, out: O) -> serde_json::Result<()> {
+        serde_json::to_writer(out, self)
+    }
+
+    /// Write the current graph using the GraphML format
+    ///
+    /// # Arguments
+    ///
+    /// - `out` - The writer receiving the GraphML graph
+    ///
+    /// # Return
+    ///
+    /// The error raised while serializing if any.
+    pub fn write_graphml<O: Write>(&self, mut out: O) -> io::Result<()> {
+        let edge_default = if self.directed {
+            "directed"
+        } else {
+            "undirected"
+        };
+
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<graphml xmlns="{GRAPHML_NAMESPACE}">"#)?;
+
+        for (key, domain) in [("value", "node"), ("distance", "node"), ("weight", "edge")] {
+            writeln!(
+                out,
+                r#"  <key id="{key}" for="{domain}" attr.name="{key}" attr.type="string"/>"#
+            )?;
+        }
+
+        writeln!(out, r#"  <graph id="G" edgedefault="{edge_default}">"#)?;
+
+        for node in &self.nodes {
+            writeln!(out, r#"    <node id="n{}">"#, node.id)?;
+            write_data(&mut out, "value", &node.value)?;
+
+            if let Some(distance) = &node.distance {
+                write_data(&mut out, "distance", distance)?;
+            }
+
+            writeln!(out, "    </node>")?;
+        }
+
+        for link in &self.links {
+            writeln!(
+                out,
+                r#"    <edge source="n{}" target="n{}">"#,
+                link.source, link.target
+            )?;
+
+            write_data(&mut out, "weight", &link.weight)?;
+            writeln!(out, "    </edge>")?;
+        }
+
+        writeln!(out, "  </graph>")?;
+        writeln!(out, "</graphml>")
+    }
+}
+
+impl<N, W> NodeLink<N, W>
+where
+    N: DeserializeOwned,
+    W: DeserializeOwned,
+{
+    /// Read a graph using the JSON node-link format
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The reader providing the JSON graph
+    ///
+    /// # Return
+    ///
+    /// The read graph or the error raised while deserializing.
+    pub fn read_json<I: io::Read>(input: I) -> serde_json::Result<Self> {
+        serde_json::from_reader(input)
+    }
+
+    /// Read a graph using the GraphML format
+    ///
+    /// Only the graphs written by
+    /// [`.write_graphml()`](NodeLink::write_graphml) are supported.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The reader providing the GraphML graph
+    ///
+    /// # Return
+    ///
+    /// The read graph or the error raised while deserializing.
+    pub fn read_graphml<I: BufRead>(input: I) -> io::Result<Self> {
+        let mut reader = Reader::from_reader(input);
+        let mut buf = Vec::default();
+
+        let mut graph = Self {
+            directed: true,
+            multigraph: false,
+            nodes: Vec::default(),
+            links: Vec::default(),
+        };
+
+        let mut current = None;
+        let mut data = None;
+
+        loop {
+            let event = reader.read_event_into(&mut buf).map_err(io::Error::other)?;
+            let is_empty = matches!(event, Event::Empty(_));
+
+            match event {
+                Event::Start(start) | Event::Empty(start) => {
+                    let element = match start.local_name().as_ref() {
+                        "graph" => {
+                            graph.directed = attribute(&start, "edgedefault")? != "undirected";
+                            None
+                        }
+
+                        "node" => {
+                            let id = parse_id(&attribute(&start, "id")?)?;
+                            Some(Element::Node(id, None, None))
+                        }
+
+                        "edge" => {
+                            let source = parse_id(&attribute(&start, "source")?)?;
+                            let target = parse_id(&attribute(&start, "target")?)?;
+                            Some(Element::Edge(source, target, None))
+                        }
+
+                        "data" if !is_empty => {
+                            data = Some((attribute(&start, "key")?, String::default()));
+                            None
+                        }
+
+                        _ => None,
+                    };
+
+                    // A self-closing element has no end event.
+                    match element {
+                        Some(element) if is_empty => element.push_into(&mut graph)?,
+                        Some(element) => current = Some(element),
+                        None => (),
+                    }
+                }
+
+                Event::Text(text) => {
+                    if let Some((_, content)) = &mut data {
+                        content.push_str(&text.xml10_content());
+                    }
+                }
+
+                Event::GeneralRef(reference) => {
+                    if let Some((_, content)) = &mut data {
+                        let reference = format!("&{};", reference.xml10_content());
+                        content.push_str(&unescape(&reference).map_err(io::Error::other)?);
+                    }
+                }
+
+                Event::End(end) => match end.local_name().as_ref() {
+                    "data" => {
+                        if let (Some((key, content)), Some(element)) = (data.take(), &mut current) {
+                            element.set(&key, content);
+                        }
+                    }
+
+                    "node" | "edge" => {
+                        if let Some(element) = current.take() {
+                            element.push_into(&mut graph)?;
+                        }
+                    }
+
+                    _ => (),
+                },
+
+                Event::Eof => break,
+                _ => (),
+            }
+
+            buf.clear();
+        }
+
+        graph.multigraph = has_parallel_links(&graph.links);
+        Ok(graph)
+    }
+}
+
+/// The GraphML XML namespace
+const GRAPHML_NAMESPACE: &str = "http://graphml.graphdrawing.org/xmlns";
+
+/// A GraphML element being read
+enum Element {
+    /// A node with its identifier, its value and its distance
+    Node(usize, Option<String>, Option<String>),
+
+    /// An edge with its source, its target and its weight
+    Edge(usize, usize, Option<String>),
+}
+
+impl Element {
+    /// Set the data of key `key` to `content`
+    ///
+    /// The unknown keys are ignored.
+    fn set(&mut self, key: &str, content: String) {
+        match (self, key) {
+            (Element::Node(_, value, _), "value") => *value = Some(content),
+            (Element::Node(_, _, distance), "distance") => *distance = Some(content),
+            (Element::Edge(_, _, weight), "weight") => *weight = Some(content),
+            _ => (),
+        }
+    }
+
+    /// Add the read element to `graph`
+    ///
+    /// A node needs a value while a missing edge weight is read as `null`.
+    fn push_into<N, W>(self, graph: &mut NodeLink<N, W>) -> io::Result<()>
+    where
+        N: DeserializeOwned,
+        W: DeserializeOwned,
+    {
+        match self {
+            Element::Node(id, value, distance) => {
+                let value = value.ok_or_else(|| invalid("Missing node value"))?;
+                let value = serde_json::from_str(&value)?;
+                let distance = distance.map(|d| serde_json::from_str(&d)).transpose()?;
+
+                graph.nodes.push(NodeLinkNode {
+                    id,
+                    value,
+                    distance,
+                });
+            }
+
+            Element::Edge(source, target, weight) => {
+                let weight = weight.as_deref().unwrap_or("null");
+                let weight = serde_json::from_str(weight)?;
+
+                graph.links.push(NodeLinkEdge {
+                    source,
+                    target,
+                    weight,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check whether several links share the same source and target
+fn has_parallel_links<W>(links: &[NodeLinkEdge<W>]) -> bool {
+    let pairs = HashSet::from_iter(links.iter().map(|link| (link.source, link.target)));
+    pairs.len() != links.len()
+}
+
+/// Write a GraphML data element encoding `value` as JSON
+fn write_data<O, T>(out: &mut O, key: &str, value: &T) -> io::Result<()>
+where
+    O: Write,
+    T: Serialize,
+{
+    let value = serde_json::to_string(value)?;
+    writeln!(out, r#"      <data key="{key}">{}</data>"#, escape(value))
+}
+
+/// Get the unescaped value of the attribute `name` of `start`
+fn attribute(start: &BytesStart<'_>, name: &str) -> io::Result<String> {
+    let attribute = start
+        .try_get_attribute(name)
+        .map_err(io::Error::other)?
+        .ok_or_else(|| invalid(&format!("Missing attribute `{name}`")))?;
+
+    let value = attribute
+        .normalized_value(XmlVersion::default())
+        .map_err(io::Error::other)?;
+    Ok(value.into_owned())
+}
+
+/// Parse a GraphML node identifier of the form `n<index>`
+fn parse_id(id: &str) -> io::Result<usize> {
+    id.strip_prefix('n')
+        .and_then(|index| index.parse().ok())
+        .ok_or_else(|| invalid(&format!("Invalid node identifier `{id}`")))
+}
+
+/// Create an [`io::Error`] reporting invalid data
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,525 @@
+//! An explicit in-memory graph
+//!
+//! # Description
+//!
+//! This module contains
+//! - [`Graph`] which is a graph stored using the
+//!   [CSR](https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format))
+//!   format,
+//! - [`GraphBuilder`] which is its builder,
+//! - [`NodeRef`] which is the [node](Node) implementation of a [`Graph`], and
+//! - [`Materialized`] which is the result of
+//!   [`.materialize()`](Node::materialize), and
+//! - [`Coordinates`] which provides an euclidean heuristic.
+//!
+//! A [`Graph`] can also be read from an edge list using
+//! [`.read_edge_list()`](Graph::read_edge_list) or from the
+//! [DIMACS shortest path](http://www.diag.uniroma1.it/challenge9/format.shtml)
+//! format using [`.read_dimacs()`](Graph::read_dimacs).
+//!
+//! See the documentation of the entities described just above for more
+//! information.
+
+mod dimacs;
+mod edge_list;
+
+pub use dimacs::Coordinates;
+
+use super::{Node, ToValue, Weighted};
+
+use std::{
+    collections::{TryReserveError, hash_map::Entry},
+    hash::{Hash, Hasher},
+    io,
+    ops::Range,
+    ptr,
+    str::FromStr,
+};
+
+type HashMap<K, V> = std::collections::HashMap<K, V, ahash::RandomState>;
+
+/// The builder for [`Graph`]
+///
+/// The edges can be added in any order. The nodes are identified by their
+/// index and the number of nodes grows as needed.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// let mut builder = GraphBuilder::new();
+///
+/// builder
+///     .add_weighted_edge(0, 1, 4)
+///     .add_weighted_edge(0, 2, 1)
+///     .add_weighted_edge(2, 1, 2)
+///     .add_weighted_edge(1, 3, 1);
+///
+/// let graph = builder.build();
+///
+/// let found = graph
+///     .node(0)
+///     .to_process::<DFS<_>>()
+///     .contains(|index: usize| index == 3);
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 4);
+/// assert!(found);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GraphBuilder<W = ()> {
+    node_count: usize,
+    edges: Vec<(usize, usize, W)>,
+}
+
+impl<W> Default for GraphBuilder<W> {
+    fn default() -> Self {
+        Self {
+            node_count: 0,
+            edges: Vec::default(),
+        }
+    }
+}
+
+impl<W> GraphBuilder<W> {
+    /// Create a new empty [`GraphBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a new node without any edge
+    ///
+    /// # Return
+    ///
+    /// The index of the added node.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of nodes overflows.
+    pub fn add_node(&mut self) -> usize {
+        self.add_nodes(1).start
+    }
+
+    /// Add `count` new nodes without any edge
+    ///
+    /// # Return
+    ///
+    /// The range of the indices of the added nodes.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of nodes overflows.
+    pub fn add_nodes(&mut self, count: usize) -> Range<usize> {
+        let start = self.node_count;
+
+        self.node_count = start
+            .checked_add(count)
+            .expect("The number of nodes overflows");
+
+        start..self.node_count
+    }
+
+    /// Add a new weighted edge
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The index of the source node
+    /// - `to` - The index of the target node
+    /// - `weight` - The weight of the edge
+    ///
+    /// # Return
+    ///
+    /// The altered [`GraphBuilder`]
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `from` or `to` is [`usize::MAX`].
+    pub fn add_weighted_edge(&mut self, from: usize, to: usize, weight: W) -> &mut Self {
+        let node_count = from
+            .max(to)
+            .checked_add(1)
+            .expect("The node index overflows");
+
+        self.node_count = self.node_count.max(node_count);
+        self.edges.push((from, to, weight));
+        self
+    }
+
+    /// Add a new edge weighted by the [`Default`] value of the weight type
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The index of the source node
+    /// - `to` - The index of the target node
+    ///
+    /// # Return
+    ///
+    /// The altered [`GraphBuilder`]
+    pub fn add_edge(&mut self, from: usize, to: usize) -> &mut Self
+    where
+        W: Default,
+    {
+        self.add_weighted_edge(from, to, W::default())
+    }
+
+    /// Build the associated [`Graph`]
+    ///
+    /// The outgoing edges of every node are kept in insertion order.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the offsets of the nodes cannot be allocated.
+    pub fn build(self) -> Graph<W> {
+        self.try_build()
+            .expect("Unable to allocate the offsets of the nodes")
+    }
+
+    /// Build the associated [`Graph`] unless the offsets of the nodes cannot
+    /// be allocated
+    fn try_build(self) -> Result<Graph<W>, TryReserveError> {
+        let mut offsets = Vec::new();
+        offsets.try_reserve_exact(self.node_count.saturating_add(1))?;
+        offsets.resize(self.node_count + 1, 0);
+
+        for &(from, _, _) in &self.edges {
+            offsets[from + 1] += 1;
+        }
+
+        for index in 0..self.node_count {
+            offsets[index + 1] += offsets[index];
+        }
+
+        let mut edges = Vec::from_iter(self.edges.into_iter().enumerate());
+        edges.sort_by_key(|&(order, (from, _, _))| (from, order));
+
+        let (targets, weights) = edges
+            .into_iter()
+            .map(|(_, (_, to, weight))| (to, weight))
+            .unzip();
+
+        Ok(Graph {
+            offsets,
+            targets,
+            weights,
+        })
+    }
+}
+
+/// A graph stored using the
+/// [CSR](https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format))
+/// format
+///
+/// The nodes are identified by their index from `0` to
+/// [`.node_count()`](Graph::node_count) (excluded). A [`Graph`] is explored
+/// through the [`NodeRef`] returned by [`.node()`](Graph::node).
+///
+/// A [`Graph`] is built using a [`GraphBuilder`] or
+/// [`.materialize()`](Node::materialize).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Graph<W = ()> {
+    /// The offset of the outgoing edges of every node followed by the number
+    /// of edges
+    offsets: Vec<usize>,
+
+    /// The target of every edge
+    targets: Vec<usize>,
+
+    /// The weight of every edge
+    weights: Vec<W>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Self {
+            offsets: vec![0],
+            targets: Vec::default(),
+            weights: Vec::default(),
+        }
+    }
+}
+
+impl<W> Graph<W> {
+    /// Get the number of nodes
+    pub fn node_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Get the number of edges
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Get the [node](NodeRef) of index `index`
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not lower than
+    /// [`.node_count()`](Graph::node_count).
+    pub fn node(&self, index: usize) -> NodeRef<'_, W> {
+        assert!(index < self.node_count(), "The node index is out of bounds");
+        NodeRef { graph: self, index }
+    }
+
+    /// Get an iterator over all the [nodes](NodeRef)
+    pub fn nodes(&self) -> impl Iterator<Item = NodeRef<'_, W>> {
+        (0..self.node_count()).map(|index| NodeRef { graph: self, index })
+    }
+
+    /// Get the outgoing edges of the node of index `index` as pairs of weight
+    /// and target index
+    pub fn edges(&self, index: usize) -> impl Iterator<Item = (&W, usize)> {
+        let range = self.offsets[index]..self.offsets[index + 1];
+
+        self.weights[range.clone()]
+            .iter()
+            .zip(self.targets[range].iter().copied())
+    }
+
+    /// Map the weight of every edge using `f`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let graph = Graph::read_edge_list("0 1\n1 2\n".as_bytes())
+    ///     .unwrap()
+    ///     .map_weights(|()| 1_u32);
+    ///
+    /// assert!(graph.node(0).weighted_outgoing().all(|(weight, _)| weight == 1));
+    /// ```
+    pub fn map_weights<V, F>(self, f: F) -> Graph<V>
+    where
+        F: FnMut(W) -> V,
+    {
+        Graph {
+            offsets: self.offsets,
+            targets: self.targets,
+            weights: self.weights.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Consume the current [`Graph`] and get all its edges as triples of
+    /// source index, target index and weight
+    #[cfg(feature = "petgraph")]
+    pub(crate) fn into_edges(self) -> impl Iterator<Item = (usize, usize, W)> {
+        let sources = self
+            .offsets
+            .windows(2)
+            .enumerate()
+            .flat_map(|(from, range)| (range[0]..range[1]).map(move |_| from))
+            .collect::<Vec<_>>();
+
+        sources
+            .into_iter()
+            .zip(self.targets)
+            .zip(self.weights)
+            .map(|((from, to), weight)| (from, to, weight))
+    }
+}
+
+/// A [node](Node) of a [`Graph`]
+///
+/// This `struct` is created by [`.node()`](Graph::node). Two [`NodeRef`]
+/// are equal if they refer to the same node of the same [`Graph`].
+#[derive(Debug)]
+pub struct NodeRef<'a, W = ()> {
+    graph: &'a Graph<W>,
+    index: usize,
+}
+
+impl<'a, W> NodeRef<'a, W> {
+    /// Get the index of the current node
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the [`Graph`] containing the current node
+    pub fn graph(&self) -> &'a Graph<W> {
+        self.graph
+    }
+}
+
+impl<W> Clone for NodeRef<'_, W> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<W> Copy for NodeRef<'_, W> {}
+
+/// [`PartialEq`] implementation for [`NodeRef`]
+///
+/// The graphs are compared by address.
+impl<W> PartialEq for NodeRef<'_, W> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.graph, other.graph) && self.index == other.index
+    }
+}
+
+/// [`Eq`] implementation for [`NodeRef`]
+///
+/// The graphs are compared by address.
+impl<W> Eq for NodeRef<'_, W> {}
+
+/// [`Hash`] implementation for [`NodeRef`]
+///
+/// Only the index is hashed.
+impl<W> Hash for NodeRef<'_, W> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+/// [`ToValue`] implementation for [`NodeRef`]
+///
+/// This implementation allows casting to the node index.
+impl<W> ToValue<usize> for NodeRef<'_, W> {
+    fn to_value(self) -> usize {
+        self.index
+    }
+}
+
+/// [`Node`] implementation for [`NodeRef`]
+impl<W> Node for NodeRef<'_, W> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let graph = self.graph;
+
+        graph
+            .edges(self.index)
+            .map(move |(_, index)| Self { graph, index })
+    }
+}
+
+/// [`Weighted`] implementation for [`NodeRef`]
+impl<W: Copy> Weighted for NodeRef<'_, W> {
+    type Weight = W;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let graph = self.graph;
+
+        graph
+            .edges(self.index)
+            .map(move |(&weight, index)| (weight, Self { graph, index }))
+    }
+}
+
+/// The reachable set of a [node](Node) stored as a [`Graph`]
+///
+/// This `struct` is created by [`.materialize()`](Node::materialize) and
+/// [`.materialize_weighted()`](Weighted::materialize_weighted). See their
+/// documentation for more information.
+///
+/// The root node is the one of index `0`.
+#[derive(Clone, Debug)]
+pub struct Materialized<N, W = ()> {
+    graph: Graph<W>,
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N, W> Materialized<N, W>
+where
+    N: Clone + Eq + Hash,
+{
+    /// Explore the reachable set of `root`
+    ///
+    /// `outgoing` is used to get the weighted outgoing nodes of every node.
+    pub(crate) fn new<X, R>(root: N, outgoing: X) -> Self
+    where
+        X: Fn(N) -> R,
+        R: Iterator<Item = (W, N)>,
+    {
+        let mut indices = HashMap::from_iter([(root.clone(), 0)]);
+        let mut nodes = vec![root];
+        let mut offsets = vec![0];
+        let mut targets = Vec::default();
+        let mut weights = Vec::default();
+
+        let mut current = 0;
+
+        while let Some(node) = nodes.get(current).cloned() {
+            for (weight, next) in outgoing(node) {
+                let index = match indices.entry(next) {
+                    Entry::Occupied(entry) => *entry.get(),
+
+                    Entry::Vacant(entry) => {
+                        nodes.push(entry.key().clone());
+                        *entry.insert(nodes.len() - 1)
+                    }
+                };
+
+                targets.push(index);
+                weights.push(weight);
+            }
+
+            offsets.push(targets.len());
+            current += 1;
+        }
+
+        Self {
+            graph: Graph {
+                offsets,
+                targets,
+                weights,
+            },
+            nodes,
+            indices,
+        }
+    }
+
+    /// Get the index of `node`
+    ///
+    /// # Return
+    ///
+    /// The index of `node` if it is reachable from the root node, [`None`]
+    /// otherwise.
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+}
+
+impl<N, W> Materialized<N, W> {
+    /// Get the underlying [`Graph`]
+    pub fn graph(&self) -> &Graph<W> {
+        &self.graph
+    }
+
+    /// Get the [node](NodeRef) associated with the root node
+    pub fn root(&self) -> NodeRef<'_, W> {
+        self.graph.node(0)
+    }
+
+    /// Get the original node of index `index`
+    pub fn get(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    /// Get the original nodes sorted by index
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Consume the current [`Materialized`] graph and get the underlying
+    /// [`Graph`] with the original nodes sorted by index
+    #[cfg(feature = "petgraph")]
+    pub(crate) fn into_parts(self) -> (Graph<W>, Vec<N>) {
+        (self.graph, self.nodes)
+    }
+}
+
+/// Parse the column `column` of the line `line` of a graph file
+fn parse<T: FromStr>(line: usize, column: Option<&str>) -> io::Result<T> {
+    let column = column.ok_or_else(|| invalid(line, "missing column"))?;
+
+    column
+        .parse()
+        .map_err(|_| invalid(line, &format!("invalid value `{column}`")))
+}
+
+/// Create an [`io::Error`] reporting invalid data at the line `line`
+fn invalid(line: usize, message: &str) -> io::Error {
+    let message = format!("line {line}: {message}");
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
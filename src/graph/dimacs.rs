@@ -0,0 +1,259 @@
+//! This module contains the
+//! [DIMACS shortest path](http://www.diag.uniroma1.it/challenge9/format.shtml)
+//! readers of [`Graph`] and [`Coordinates`]
+
+use super::{Graph, GraphBuilder, invalid, parse};
+use crate::ToValue;
+
+use std::{
+    io::{self, BufRead},
+    str::{FromStr, SplitWhitespace},
+};
+
+impl<W: FromStr> Graph<W> {
+    /// Read a weighted graph using the DIMACS `.gr` format
+    ///
+    /// The single problem line `p sp <nodes> <edges>` is followed by exactly
+    /// `<edges>` arc lines `a <source> <target> <weight>`. The DIMACS node
+    /// identifiers start from `1` while the node indices of the read [`Graph`]
+    /// start from `0`.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The reader providing the `.gr` file
+    ///
+    /// # Return
+    ///
+    /// The read graph or the error raised while reading.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let input = "c A small graph\np sp 4 4\na 1 2 4\na 1 3 1\na 3 2 2\na 2 4 1\n";
+    /// let graph = Graph::<u32>::read_dimacs(input.as_bytes()).unwrap();
+    ///
+    /// let found = graph
+    ///     .node(0)
+    ///     .to_process::<DFS<_>>()
+    ///     .contains(|index: usize| index == 3);
+    ///
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert!(found);
+    /// ```
+    pub fn read_dimacs<I: BufRead>(input: I) -> io::Result<Self> {
+        let mut builder = GraphBuilder::new();
+
+        // The declared node and edge counts with the problem line
+        let mut problem = None;
+
+        read(input, |kind, mut columns, line| match kind {
+            "p" => {
+                if problem.is_some() {
+                    return Err(invalid(line, "duplicate problem line"));
+                }
+
+                if columns.next() != Some("sp") {
+                    return Err(invalid(line, "expected a shortest path problem"));
+                }
+
+                let node_count = parse(line, columns.next())?;
+                let edge_count = parse(line, columns.next())?;
+
+                builder.add_nodes(node_count);
+                problem = Some((node_count, edge_count, line));
+                Ok(())
+            }
+
+            "a" => {
+                let (node_count, edge_count, _) =
+                    problem.ok_or_else(|| invalid(line, "missing problem line"))?;
+
+                if builder.edges.len() == edge_count {
+                    return Err(invalid(line, "more arcs than declared"));
+                }
+
+                let from = parse_id(line, columns.next(), node_count)?;
+                let to = parse_id(line, columns.next(), node_count)?;
+                let weight = parse(line, columns.next())?;
+
+                builder.add_weighted_edge(from, to, weight);
+                Ok(())
+            }
+
+            _ => Err(invalid(line, "unknown line kind")),
+        })?;
+
+        let Some((_, edge_count, line)) = problem else {
+            return Ok(builder.build());
+        };
+
+        if builder.edges.len() < edge_count {
+            return Err(invalid(line, "fewer arcs than declared"));
+        }
+
+        builder
+            .try_build()
+            .map_err(|_| invalid(line, "too many nodes"))
+    }
+}
+
+/// The coordinates of the nodes of a [`Graph`]
+///
+/// The coordinates are read using the DIMACS `.co` format and provide an
+/// [euclidean heuristic](Coordinates::heuristic).
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// let graph = "p sp 3 3\na 1 2 5\na 2 3 5\na 1 3 12\n";
+/// let graph = Graph::<u32>::read_dimacs(graph.as_bytes()).unwrap();
+///
+/// let coordinates = "p aux sp co 3\nv 1 0 0\nv 2 3 4\nv 3 6 8\n";
+/// let coordinates = Coordinates::read_dimacs(coordinates.as_bytes()).unwrap();
+///
+/// let start = graph.node(0).with_heuristic(coordinates.heuristic(2));
+///
+/// assert_eq!(start.heuristic(), 10.0);
+/// assert_eq!(coordinates.distance(0, 1), 5.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Coordinates {
+    points: Vec<(f64, f64)>,
+}
+
+impl Coordinates {
+    /// Read coordinates using the DIMACS `.co` format
+    ///
+    /// The single problem line `p aux sp co <nodes>` is followed by the
+    /// coordinate lines `v <node> <x> <y>`. The DIMACS node identifiers start from `1`
+    /// while the node indices start from `0`.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The reader providing the `.co` file
+    ///
+    /// # Return
+    ///
+    /// The read coordinates or the error raised while reading.
+    pub fn read_dimacs<I: BufRead>(input: I) -> io::Result<Self> {
+        let mut points = None;
+
+        read(input, |kind, mut columns, line| match kind {
+            "p" => {
+                if points.is_some() {
+                    return Err(invalid(line, "duplicate problem line"));
+                }
+
+                if columns.by_ref().take(3).ne(["aux", "sp", "co"]) {
+                    return Err(invalid(line, "expected a coordinate problem"));
+                }
+
+                let count = parse(line, columns.next())?;
+                let mut allocated = Vec::new();
+
+                allocated
+                    .try_reserve_exact(count)
+                    .map_err(|_| invalid(line, "too many nodes"))?;
+
+                allocated.resize(count, (0.0, 0.0));
+                points = Some(allocated);
+                Ok(())
+            }
+
+            "v" => {
+                let points = points
+                    .as_mut()
+                    .ok_or_else(|| invalid(line, "missing problem line"))?;
+
+                let index = parse_id(line, columns.next(), points.len())?;
+                let x = parse(line, columns.next())?;
+                let y = parse(line, columns.next())?;
+
+                points[index] = (x, y);
+                Ok(())
+            }
+
+            _ => Err(invalid(line, "unknown line kind")),
+        })?;
+
+        Ok(Self {
+            points: points.unwrap_or_default(),
+        })
+    }
+
+    /// Get the number of nodes
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Check whether there is no node
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Get the coordinates of the node of index `index`
+    pub fn get(&self, index: usize) -> Option<(f64, f64)> {
+        self.points.get(index).copied()
+    }
+
+    /// Get the euclidean distance between the nodes of indices `from` and `to`
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `from` or `to` is out of bounds.
+    pub fn distance(&self, from: usize, to: usize) -> f64 {
+        let (from_x, from_y) = self.points[from];
+        let (to_x, to_y) = self.points[to];
+
+        (to_x - from_x).hypot(to_y - from_y)
+    }
+
+    /// Get the euclidean heuristic leading to the node of index `goal`
+    ///
+    /// The returned closure can be attached to a [node](crate::Node) using
+    /// [`.with_heuristic()`](crate::Node::with_heuristic). It is admissible
+    /// as long as the weight of every edge is not lower than the euclidean
+    /// distance between its nodes.
+    pub fn heuristic<N>(&self, goal: usize) -> impl Fn(&N) -> f64 + Copy + '_
+    where
+        N: Copy + ToValue<usize>,
+    {
+        move |&node| self.distance(node.to_value(), goal)
+    }
+}
+
+/// Read a DIMACS file
+///
+/// `handle` is called with the kind, the remaining columns and the line number
+/// of every non-comment line.
+fn read<I, H>(input: I, mut handle: H) -> io::Result<()>
+where
+    I: BufRead,
+    H: FnMut(&str, SplitWhitespace<'_>, usize) -> io::Result<()>,
+{
+    for (index, content) in input.lines().enumerate() {
+        let content = content?;
+        let mut columns = content.split_whitespace();
+        let line = index + 1;
+
+        match columns.next() {
+            None | Some("c") => (),
+            Some(kind) => handle(kind, columns, line)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a DIMACS node identifier into a node index lower than `node_count`
+fn parse_id(line: usize, column: Option<&str>, node_count: usize) -> io::Result<usize> {
+    let id: usize = parse(line, column)?;
+
+    id.checked_sub(1)
+        .filter(|&index| index < node_count)
+        .ok_or_else(|| invalid(line, "node identifier out of bounds"))
+}
@@ -0,0 +1,128 @@
+//! This module contains the edge list readers of [`Graph`]
+
+use super::{Graph, GraphBuilder, invalid, parse};
+
+use std::{
+    io::{self, BufRead},
+    str::{FromStr, SplitWhitespace},
+};
+
+impl Graph {
+    /// Read an unweighted graph from an edge list
+    ///
+    /// Every line contains the indices of the source and the target nodes of
+    /// an edge separated by whitespaces. The remaining columns are ignored as
+    /// well as the empty lines and the ones starting with `#` or `%`.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The reader providing the edge list
+    ///
+    /// # Return
+    ///
+    /// The read graph or the error raised while reading.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let input = "# A small cycle\n0 1\n1 2\n2 0\n";
+    /// let graph = Graph::read_edge_list(input.as_bytes()).unwrap();
+    ///
+    /// let reachable = graph.node(1).to_process::<DFS<_>>().reachable();
+    /// assert_eq!(reachable.len(), 3);
+    /// ```
+    pub fn read_edge_list<I: BufRead>(input: I) -> io::Result<Self> {
+        read(input, |builder, from, to, _, _| {
+            builder.add_edge(from, to);
+            Ok(())
+        })
+    }
+}
+
+impl<W: FromStr> Graph<W> {
+    /// Read a weighted graph from an edge list
+    ///
+    /// Every line contains the indices of the source and the target nodes of
+    /// an edge followed by its weight separated by whitespaces. The remaining
+    /// columns are ignored as well as the empty lines and the ones starting
+    /// with `#` or `%`.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` - The reader providing the edge list
+    ///
+    /// # Return
+    ///
+    /// The read graph or the error raised while reading.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let input = "0 1 4\n0 2 1\n2 1 2\n";
+    /// let graph = Graph::<u32>::read_weighted_edge_list(input.as_bytes()).unwrap();
+    ///
+    /// let edges = graph
+    ///     .node(0)
+    ///     .weighted_outgoing()
+    ///     .map(|(weight, node)| (weight, node.index()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(edges, [(4, 1), (1, 2)]);
+    /// ```
+    pub fn read_weighted_edge_list<I: BufRead>(input: I) -> io::Result<Self> {
+        read(input, |builder, from, to, mut columns, line| {
+            let weight = parse(line, columns.next())?;
+            builder.add_weighted_edge(from, to, weight);
+            Ok(())
+        })
+    }
+}
+
+/// Read an edge list
+///
+/// `add` is called with the source, the target, the remaining columns and the
+/// line number of every edge. The node indices are checked first so that the
+/// node count neither overflows nor exceeds what can be allocated.
+fn read<I, W, A>(input: I, mut add: A) -> io::Result<Graph<W>>
+where
+    I: BufRead,
+    A: FnMut(&mut GraphBuilder<W>, usize, usize, SplitWhitespace<'_>, usize) -> io::Result<()>,
+{
+    let mut builder = GraphBuilder::new();
+
+    // The largest node index with its line
+    let mut largest = None;
+
+    for (index, content) in input.lines().enumerate() {
+        let content = content?;
+        let content = content.trim();
+        let line = index + 1;
+
+        if content.is_empty() || content.starts_with(['#', '%']) {
+            continue;
+        }
+
+        let mut columns = content.split_whitespace();
+        let from: usize = parse(line, columns.next())?;
+        let to: usize = parse(line, columns.next())?;
+
+        if from.max(to) == usize::MAX {
+            return Err(invalid(line, "node index out of bounds"));
+        }
+
+        if largest.is_none_or(|(largest, _)| from.max(to) > largest) {
+            largest = Some((from.max(to), line));
+        }
+
+        add(&mut builder, from, to, columns, line)?;
+    }
+
+    builder.try_build().map_err(|_| {
+        let (_, line) = largest.unwrap_or_default();
+        invalid(line, "too many nodes")
+    })
+}
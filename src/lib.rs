@@ -3,11 +3,32 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(missing_docs)]
 
+pub mod adapter;
+pub mod export;
+pub mod graph;
 pub mod nodifyied;
+
+#[cfg(feature = "petgraph")]
+pub mod petgraph_interop;
+
 pub mod prelude;
 pub mod process;
+pub mod product;
 
+#[cfg(feature = "derive")]
+pub use nodify_derive::{Node, Weighted};
+
+use adapter::{
+    BoxedNode, Canonicalized, FilterOutgoing, MapWeight, Reversed, TakeDepth, UnitWeights,
+    WithDepth, WithHeuristic,
+};
+use graph::Materialized;
 use process::Process;
+use product::{InterleavedProduct, SyncProduct};
+use std::{
+    any::{Any, TypeId},
+    hash::{Hash, Hasher},
+};
 
 /// A trait representing a [graph node](Node).
 ///
@@ -27,6 +48,233 @@ pub trait Node {
     {
         Process::from_node(self)
     }
+
+    /// Wrap the current [node](Node) into a [`FilterOutgoing`] one
+    ///
+    /// Only the edges verifying `pred` are kept. `pred` is called with the
+    /// source and the target of every edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    /// use std::iter::once;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         once(Number(self.0 + 1))
+    ///     }
+    /// }
+    ///
+    /// let found = Number(0)
+    ///     .filter_outgoing(|_, &Number(next)| next < 10)
+    ///     .to_process::<DFS<_>>()
+    ///     .contains(|Number(i)| i == 42);
+    ///
+    /// assert!(!found);
+    /// ```
+    fn filter_outgoing<P>(self, pred: P) -> FilterOutgoing<Self, P>
+    where
+        P: Copy + Fn(&Self, &Self) -> bool,
+        Self: Sized,
+    {
+        FilterOutgoing::new(self, pred)
+    }
+
+    /// Wrap the current [node](Node) into a [`UnitWeights`] one
+    ///
+    /// Every edge is weighted by `1` so that the weighted
+    /// [`Process`es](Process) can be applied to an unweighted graph.
+    fn with_unit_weights(self) -> UnitWeights<Self>
+    where
+        Self: Sized,
+    {
+        UnitWeights::new(self)
+    }
+
+    /// Wrap the current [node](Node) into a [`WithDepth`] one
+    ///
+    /// The depth of the node, i.e. the number of edges leading to it from the
+    /// current node, is part of the state. Hence, a cyclic graph becomes
+    /// infinite and has to be bounded using
+    /// [`.take_depth()`](Node::take_depth) in order to be fully explored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    /// use std::iter::once;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Cell(u8);
+    ///
+    /// impl Node for Cell {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         once(Cell((self.0 + 1) % 3))
+    ///     }
+    /// }
+    ///
+    /// let found = Cell(0)
+    ///     .with_depth()
+    ///     .to_process::<DFS<_>>()
+    ///     .find_first(|(Cell(i), depth)| i == 0 && depth > 0);
+    ///
+    /// assert_eq!(found.map(|node| node.depth()), Some(3));
+    /// ```
+    fn with_depth(self) -> WithDepth<Self>
+    where
+        Self: Sized,
+    {
+        WithDepth::new(self)
+    }
+
+    /// Wrap the current [node](Node) into a [`TakeDepth`] one
+    ///
+    /// The graph is unfolded until the depth `max_depth`, i.e. the nodes at
+    /// depth `max_depth` have no outgoing node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    /// use std::iter::once;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         once(Number(self.0 + 1))
+    ///     }
+    /// }
+    ///
+    /// let reachable = Number(0)
+    ///     .take_depth(3)
+    ///     .to_process::<DFS<_>>()
+    ///     .reachable();
+    ///
+    /// assert_eq!(reachable.len(), 4);
+    /// ```
+    fn take_depth(self, max_depth: usize) -> TakeDepth<Self>
+    where
+        Self: Sized,
+    {
+        TakeDepth::new(self, max_depth)
+    }
+
+    /// Create the [synchronous product](SyncProduct) of the current
+    /// [node](Node) and `other`
+    ///
+    /// Both nodes move at the same time and only the pairs of outgoing nodes
+    /// verifying `compatible` are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Counter(u32);
+    ///
+    /// impl Node for Counter {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         [Counter((self.0 + 1) % 6), Counter((self.0 + 2) % 6)].into_iter()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// enum Parity {
+    ///     Even,
+    ///     Odd,
+    /// }
+    ///
+    /// impl Node for Parity {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         [Parity::Even, Parity::Odd].into_iter()
+    ///     }
+    /// }
+    ///
+    /// let is_consistent = |&Counter(n): &Counter, &parity: &Parity| {
+    ///     (n % 2 == 0) == (parity == Parity::Even)
+    /// };
+    ///
+    /// let reachable = Counter(0)
+    ///     .sync_product(Parity::Even, is_consistent)
+    ///     .to_process::<DFS<_>>()
+    ///     .reachable();
+    ///
+    /// assert_eq!(reachable.len(), 6);
+    /// ```
+    fn sync_product<B, P>(self, other: B, compatible: P) -> SyncProduct<Self, B, P>
+    where
+        Self: Sized,
+        P: Fn(&Self, &B) -> bool,
+    {
+        SyncProduct::new(self, other, compatible)
+    }
+
+    /// Create the [interleaving product](InterleavedProduct) of the current
+    /// [node](Node) and `other`
+    ///
+    /// Only one node moves at a time.
+    fn interleave<B>(self, other: B) -> InterleavedProduct<Self, B>
+    where
+        Self: Sized,
+    {
+        InterleavedProduct::new(self, other)
+    }
+
+    /// Wrap the current [node](Node) into a [`WithHeuristic`] one
+    ///
+    /// The returned node implements [`Heuristic`] using `heuristic`.
+    fn with_heuristic<H, C>(self, heuristic: H) -> WithHeuristic<Self, H>
+    where
+        H: Copy + Fn(&Self) -> C,
+        Self: Sized,
+    {
+        WithHeuristic::new(self, heuristic)
+    }
+
+    /// Explore the reachable set of the current [node](Node) and store it as a
+    /// [`Graph`](graph::Graph)
+    ///
+    /// The returned [`Materialized`] graph can then be explored many times
+    /// without calling [`.outgoing()`](Node::outgoing) again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         [Number((self.0 + 1) % 10), Number(self.0 * 2 % 10)].into_iter()
+    ///     }
+    /// }
+    ///
+    /// let materialized = Number(1).materialize();
+    /// let target = materialized.index_of(&Number(7)).unwrap();
+    ///
+    /// let found = materialized
+    ///     .root()
+    ///     .to_process::<DFS<_>>()
+    ///     .contains(|index: usize| index == target);
+    ///
+    /// assert_eq!(materialized.graph().node_count(), 10);
+    /// assert!(found);
+    /// ```
+    fn materialize(self) -> Materialized<Self>
+    where
+        Self: Clone + Eq + Hash,
+    {
+        Materialized::new(self, |node| node.outgoing().map(|next| ((), next)))
+    }
 }
 
 /// A trait implemention allowing [`ToValue`] to be
@@ -41,6 +289,10 @@ impl<N: Node> ToValue<Self> for N {
 ///
 /// This trait aims to support [`Process`] predicate with an input type
 /// different from the node type.
+///
+/// It is implemented reflexively for every [`Node`]. A node only implementing
+/// another node trait (e.g. [`TryNode`] or `AsyncNode`) has to implement
+/// `ToValue<Self>` itself in order to be given to the predicates.
 pub trait ToValue<I> {
     /// Convert the [`Node`] to the given type
     fn to_value(self) -> I;
@@ -128,4 +380,569 @@ pub trait Weighted {
 
     /// Get the outgoing edges of the current [node](Weighted)
     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)>;
+
+    /// Wrap the current [node](Weighted) into a [`MapWeight`] one
+    ///
+    /// Every weight is mapped using `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Weighted for Number {
+    ///     type Weight = u32;
+    ///
+    ///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+    ///         [(1, Number(self.0 + 1)), (3, Number(self.0 * 2))].into_iter()
+    ///     }
+    /// }
+    ///
+    /// let edges = Number(3)
+    ///     .map_weight(|w| 10 * w)
+    ///     .weighted_outgoing()
+    ///     .map(|(w, node)| (w, node.into_inner()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(edges, [(10, Number(4)), (30, Number(6))]);
+    /// ```
+    fn map_weight<F, V>(self, f: F) -> MapWeight<Self, F>
+    where
+        F: Copy + Fn(Self::Weight) -> V,
+        Self: Sized,
+    {
+        MapWeight::new(self, f)
+    }
+
+    /// Explore the reachable set of the current [node](Weighted) and store it
+    /// as a weighted [`Graph`](graph::Graph)
+    ///
+    /// See [`.materialize()`](Node::materialize) for more information.
+    fn materialize_weighted(self) -> Materialized<Self, Self::Weight>
+    where
+        Self: Clone + Eq + Hash,
+    {
+        Materialized::new(self, Self::weighted_outgoing)
+    }
+}
+
+/// A trait representing a [graph node](Incoming) whose predecessors are known.
+///
+/// # Description
+///
+/// This trait is based on the [`.incoming()`](Incoming::incoming), which allows
+/// to get the incoming nodes of the current one, i.e. the nodes whose outgoing
+/// nodes contain the current one.
+///
+/// The [`Process`es](Process) only travel the graph forward. In order to
+/// explore it backward, e.g. to compute all the nodes that can reach a goal,
+/// the goal node has to be wrapped using [`.reversed()`](Incoming::reversed).
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// /// A counter incremented modulo 10 which cannot reach 5 from 0
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Counter(u8);
+///
+/// impl Node for Counter {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         (self.0 != 4).then_some(Counter((self.0 + 1) % 10)).into_iter()
+///     }
+/// }
+///
+/// impl Incoming for Counter {
+///     fn incoming(self) -> impl Iterator<Item = Self> {
+///         (self.0 != 5).then_some(Counter((self.0 + 9) % 10)).into_iter()
+///     }
+/// }
+///
+/// let mut can_reach_goal = Counter(3)
+///     .reversed()
+///     .to_process::<DFS<_>>()
+///     .reachable()
+///     .into_iter()
+///     .map(|node| node.into_inner().0)
+///     .collect::<Vec<_>>();
+///
+/// can_reach_goal.sort();
+///
+/// assert_eq!(can_reach_goal, [0, 1, 2, 3, 5, 6, 7, 8, 9]);
+/// ```
+pub trait Incoming {
+    /// Get the incoming neighbors of the current [node](Incoming)
+    fn incoming(self) -> impl Iterator<Item = Self>;
+
+    /// Wrap the current [node](Incoming) into a [`Reversed`] one
+    fn reversed(self) -> Reversed<Self>
+    where
+        Self: Sized,
+    {
+        Reversed::new(self)
+    }
+}
+
+/// A trait representing a [weighted graph node](WeightedIncoming) whose
+/// predecessors are known.
+///
+/// This trait is the weighted counterpart of [`Incoming`]. See its
+/// documentation for more information.
+pub trait WeightedIncoming {
+    /// The type used as weight
+    type Weight;
+
+    /// Get the incoming edges of the current [node](WeightedIncoming)
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)>;
+}
+
+/// A trait representing a [graph node](Labeled) whose outgoing edges are
+/// labeled.
+///
+/// # Description
+///
+/// This trait is based on the
+/// [`.labeled_outgoing()`](Labeled::labeled_outgoing), which allows to get the
+/// outgoing nodes of the current one with the label of the associated edge,
+/// e.g. the action leading to the outgoing node. Using this method, the
+/// [`FindLabels`](process::FindLabels) process gives back the labels of the
+/// edges leading to the found node.
+///
+/// # Relation with [`Node`]
+///
+/// As for [`Weighted`], a struct implementing [`Labeled`] should implement
+/// [`Node`] in order to be explored by the other processes.
+/// [`FindLabels`](process::FindLabels) only follows the labeled edges, so the
+/// outgoing nodes of both methods have to be the same for the found paths to
+/// agree.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// enum Move {
+///     Double,
+///     Increment,
+/// }
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Number(u32);
+///
+/// impl Labeled for Number {
+///     type Label = Move;
+///
+///     fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+///         [
+///             (Move::Increment, Number(self.0 + 1)),
+///             (Move::Double, Number(2 * self.0)),
+///         ]
+///         .into_iter()
+///         .filter(|&(_, Number(i))| i <= 10)
+///     }
+/// }
+///
+/// impl Node for Number {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         self.labeled_outgoing().map(|(_, outgoing)| outgoing)
+///     }
+/// }
+///
+/// let moves = Number(1)
+///     .to_process::<DFS<_>>()
+///     .find_labels(|Number(i)| i == 4)
+///     .unwrap();
+///
+/// let Number(i) = moves.iter().fold(Number(1), |Number(i), m| match m {
+///     Move::Double => Number(2 * i),
+///     Move::Increment => Number(i + 1),
+/// });
+///
+/// assert_eq!(i, 4);
+/// ```
+pub trait Labeled: Sized {
+    /// The type used as label
+    type Label;
+
+    /// Get the outgoing labeled edges of the current [node](Labeled)
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)>;
+}
+
+/// A trait representing a [weighted graph node](WeightedLabeled) whose
+/// outgoing edges are labeled.
+///
+/// This trait is the weighted counterpart of [`Labeled`]. A struct
+/// implementing [`WeightedLabeled`] should implement [`Weighted`] in order to
+/// be explored by the other processes. The outgoing edges of both methods have
+/// to be the same for the found paths to agree.
+pub trait WeightedLabeled: Sized {
+    /// The type used as weight
+    type Weight;
+
+    /// The type used as label
+    type Label;
+
+    /// Get the outgoing weighted and labeled edges of the current
+    /// [node](WeightedLabeled)
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)>;
+}
+
+/// A trait representing a [graph node](TryNode) whose outgoing nodes may fail
+/// to be generated.
+///
+/// # Description
+///
+/// This trait is the fallible counterpart of [`Node`]. It is based on the
+/// [`.try_outgoing()`](TryNode::try_outgoing), which yields either an outgoing
+/// node or the error raised while generating it, e.g. when parsing a puzzle,
+/// calling a simulator or reading from a file-backed graph.
+///
+/// The fallible [`Process`es](Process) (e.g.
+/// [`TryFindAny`](process::TryFindAny)) stop at the first raised error and
+/// return it.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Counter(u8);
+///
+/// impl ToValue<Self> for Counter {
+///     fn to_value(self) -> Self {
+///         self
+///     }
+/// }
+///
+/// impl TryNode for Counter {
+///     type Error = &'static str;
+///
+///     fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+///         std::iter::once(self.0.checked_add(1).map(Counter).ok_or("Overflow"))
+///     }
+/// }
+///
+/// let process = DFS::from_node(Counter(0));
+///
+/// assert_eq!(process.try_contains(|Counter(i)| i == 42), Ok(true));
+/// assert_eq!(process.try_contains(|_: Counter| false), Err("Overflow"));
+/// ```
+pub trait TryNode: Sized {
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Get the outgoing neighbors of the current [node](TryNode)
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>>;
+}
+
+/// A trait representing a [weighted graph node](TryWeighted) whose outgoing
+/// edges may fail to be generated.
+///
+/// This trait is the fallible counterpart of [`Weighted`]. See [`TryNode`] for
+/// more information.
+pub trait TryWeighted: Sized {
+    /// The type used as weight
+    type Weight;
+
+    /// The error type raised while generating the outgoing edges
+    type Error;
+
+    /// Get the outgoing edges of the current [node](TryWeighted)
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>>;
+}
+
+/// A trait representing a [graph node](AsyncNode) whose outgoing nodes are
+/// generated asynchronously.
+///
+/// # Description
+///
+/// This trait is the asynchronous counterpart of [`Node`]. It is useful when
+/// the outgoing nodes are obtained through I/O, e.g. RPC calls to a simulator,
+/// database lookups or HTTP requests. The asynchronous
+/// [`Process`es](Process) (e.g. [`AsyncBFS`](process::AsyncBFS)) keep several
+/// of these expansions in flight.
+///
+/// The method can be implemented using an `async fn`. It is not named
+/// `outgoing` so that a type can implement both [`Node`] and [`AsyncNode`].
+///
+/// # Example
+///
+/// ```
+/// use futures::executor::block_on;
+/// use nodify::prelude::*;
+/// use std::iter::once;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Counter(u32);
+///
+/// impl ToValue<Self> for Counter {
+///     fn to_value(self) -> Self {
+///         self
+///     }
+/// }
+///
+/// impl AsyncNode for Counter {
+///     async fn async_outgoing(self) -> impl Iterator<Item = Self> {
+///         once(Counter(self.0 + 1))
+///     }
+/// }
+///
+/// let found = block_on(AsyncBFS::from_node(Counter(0)).contains(|Counter(i)| i == 42));
+///
+/// assert!(found);
+/// ```
+#[cfg(feature = "async")]
+pub trait AsyncNode: Sized {
+    /// Get the outgoing neighbors of the current [node](AsyncNode)
+    fn async_outgoing(self) -> impl Future<Output = impl Iterator<Item = Self>>;
+}
+
+/// A trait representing a [graph node](AsyncTryNode) whose outgoing nodes are
+/// generated asynchronously and may fail to be generated.
+///
+/// This trait is the fallible counterpart of [`AsyncNode`]. An expansion
+/// either gives all the outgoing nodes or fails as a whole, e.g. when a
+/// request is refused. The fallible asynchronous [`Process`es](Process) (e.g.
+/// [`AsyncTryFindAny`](process::AsyncTryFindAny)) stop at the first raised
+/// error and return it.
+///
+/// # Example
+///
+/// ```
+/// use futures::executor::block_on;
+/// use nodify::prelude::*;
+/// use std::iter::once;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Counter(u8);
+///
+/// impl ToValue<Self> for Counter {
+///     fn to_value(self) -> Self {
+///         self
+///     }
+/// }
+///
+/// impl AsyncTryNode for Counter {
+///     type Error = &'static str;
+///
+///     async fn try_async_outgoing(self) -> Result<impl Iterator<Item = Self>, Self::Error> {
+///         let next = self.0.checked_add(1).ok_or("Overflow")?;
+///         Ok(once(Counter(next)))
+///     }
+/// }
+///
+/// let process = AsyncBFS::from_node(Counter(0));
+///
+/// assert_eq!(block_on(process.try_contains(|Counter(i)| i == 42)), Ok(true));
+/// assert_eq!(block_on(process.try_contains(|_: Counter| false)), Err("Overflow"));
+/// ```
+#[cfg(feature = "async")]
+pub trait AsyncTryNode: Sized {
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Get the outgoing neighbors of the current [node](AsyncTryNode)
+    fn try_async_outgoing(
+        self,
+    ) -> impl Future<Output = Result<impl Iterator<Item = Self>, Self::Error>>;
+}
+
+/// A trait representing a [node](Canonical) with a canonical representative.
+///
+/// # Description
+///
+/// Many graphs contain symmetric states, e.g. rotated boards or permuted
+/// identical processes. [`.canonical()`](Canonical::canonical) maps a node to
+/// the representative of its symmetry class so that all the nodes of a same
+/// class are visited only once.
+///
+/// The [`Process`es](Process) do not use this trait directly: the root node
+/// has to be wrapped using [`.canonicalized()`](Canonical::canonicalized).
+/// Every outgoing node of the returned [`Canonicalized`] node is then
+/// canonicalized before reaching the visited-set check of the
+/// [`Process`].
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// /// A position on a ring of 12 cells where `i` and `12 - i` are symmetric
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Cell(u8);
+///
+/// impl Node for Cell {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         [Cell((self.0 + 1) % 12), Cell((self.0 + 11) % 12)].into_iter()
+///     }
+/// }
+///
+/// impl Canonical for Cell {
+///     fn canonical(self) -> Self {
+///         Cell(self.0.min((12 - self.0) % 12))
+///     }
+/// }
+///
+/// let path = Cell(0)
+///     .canonicalized()
+///     .to_process::<DFS<_>>()
+///     .find_path(|Cell(i)| i == 6)
+///     .unwrap();
+///
+/// assert!(path.iter().all(|node| node.into_inner().0 <= 6));
+/// ```
+pub trait Canonical {
+    /// Get the canonical representative of the current [node](Canonical)
+    fn canonical(self) -> Self;
+
+    /// Wrap the current [node](Canonical) into a [`Canonicalized`] one
+    ///
+    /// The current node is itself canonicalized.
+    fn canonicalized(self) -> Canonicalized<Self>
+    where
+        Self: Sized,
+    {
+        Canonicalized::new(self)
+    }
+}
+
+/// A trait representing a [node](Heuristic) able to estimate its distance to a
+/// goal.
+///
+/// # Description
+///
+/// This estimation is meant to be used by informed searches such as
+/// [A*](https://en.wikipedia.org/wiki/A*_search_algorithm). In order to find
+/// shortest paths, [`.heuristic()`](Heuristic::heuristic) should never
+/// overestimate the distance to the goal.
+///
+/// Instead of implementing this trait, a closure can be attached to any
+/// [node](Node) using [`.with_heuristic()`](Node::with_heuristic).
+pub trait Heuristic {
+    /// The type of the estimated distance
+    type Cost;
+
+    /// Estimate the distance from the current [node](Heuristic) to the goal
+    fn heuristic(&self) -> Self::Cost;
+}
+
+/// A dyn-compatible version of [`Node`]
+///
+/// # Description
+///
+/// As [`.outgoing()`](Node::outgoing) returns an `impl Iterator`, [`Node`]
+/// cannot be used as a trait object. This trait returns boxed outgoing
+/// [`BoxedNode`]s instead so that a graph can mix different state types, e.g.
+/// states provided by plugins loaded at runtime. [`BoxedNode`] implements
+/// [`Node`] but is not [`Copy`]. Hence, the [`Process`es](Process) explore it
+/// through the [`Copy`] handles given by a
+/// [`DynInterner`](adapter::DynInterner), which interns the explored nodes on
+/// the fly, or once [materialized](Node::materialize) if the graph is finite.
+///
+/// This trait is implemented for every [`Node`] which is [`Clone`], [`Eq`],
+/// [`Hash`], [`Send`], [`Sync`] and `'static`. The other types only have to
+/// implement [`.dyn_outgoing()`](DynNode::dyn_outgoing) as [`DynIdentity`] is
+/// implemented for every [`Clone`], [`Eq`] and [`Hash`] [`DynNode`].
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// struct Room(u32);
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// struct Door(&'static str, u32);
+///
+/// impl DynNode for Room {
+///     fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_> {
+///         let doors = [Door("left", self.0 + 1), Door("right", self.0 * 2)];
+///         Box::new(doors.into_iter().filter(|door| door.1 < 10).map(BoxedNode::new))
+///     }
+/// }
+///
+/// impl DynNode for Door {
+///     fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_> {
+///         Box::new(std::iter::once(BoxedNode::new(Room(self.1))))
+///     }
+/// }
+///
+/// let interner = DynInterner::new();
+/// let process = interner.intern(BoxedNode::new(Room(1))).to_process::<DFS<_>>();
+///
+/// let found = process.contains(|node: DynHandle| node.downcast() == Some(Room(7)));
+///
+/// let doors = process
+///     .reachable()
+///     .iter()
+///     .filter(|node| node.downcast::<Door>().is_some())
+///     .count();
+///
+/// assert!(found);
+/// assert_eq!(doors, 12);
+/// ```
+pub trait DynNode: DynIdentity {
+    /// Get the boxed outgoing nodes of the current [node](DynNode)
+    fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_>;
+}
+
+/// [`DynNode`] implementation for every [`Node`]
+///
+/// The outgoing nodes of a clone of the current node are boxed.
+impl<N> DynNode for N
+where
+    N: Clone + Eq + Hash + Node + Send + Sync + 'static,
+{
+    fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_> {
+        Box::new(self.clone().outgoing().map(BoxedNode::new))
+    }
+}
+
+/// The dyn-compatible identity of a [`DynNode`]
+///
+/// This trait provides the dyn-compatible counterparts of [`Clone`], [`Eq`]
+/// and [`Hash`] used by [`BoxedNode`]. It is implemented for every [`Clone`],
+/// [`Eq`] and [`Hash`] [`DynNode`].
+pub trait DynIdentity: Any + Send + Sync {
+    /// Clone the current [node](DynNode) into a [`BoxedNode`]
+    fn dyn_clone(&self) -> BoxedNode;
+
+    /// Check whether the current [node](DynNode) is equal to `other`
+    ///
+    /// Nodes of different types are never equal.
+    fn dyn_eq(&self, other: &dyn DynNode) -> bool;
+
+    /// Feed the current [node](DynNode) and its type into `state`
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+/// [`DynIdentity`] implementation for every [`Clone`], [`Eq`] and [`Hash`]
+/// [`DynNode`]
+impl<N> DynIdentity for N
+where
+    N: Clone + DynNode + Eq + Hash,
+{
+    fn dyn_clone(&self) -> BoxedNode {
+        BoxedNode::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn DynNode) -> bool {
+        (other as &dyn Any)
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        TypeId::of::<Self>().hash(&mut state);
+        self.hash(&mut state);
+    }
 }
@@ -3,17 +3,30 @@
 //! # Description
 //!
 //! This module contains
-//! - [`Nodifyied`] which is the _nodifyied_ [node](Node) implementation, and
-//! - with [its builder](NodifyiedBuilder).
+//! - [`Nodifyied`] which is the _nodifyied_ [node](Node) implementation,
+//! - with [its builder](NodifyiedBuilder),
+//! - [`OwnedNodifyied`] which is its variant owning the wrapper functions, and
+//! - [`WeightedOutgoing`] which marks a
+//!   [`.weighted_outgoing()`](Weighted::weighted_outgoing) wrapper function.
+//!
+//! Besides the mandatory [`.outgoing()`](Node::outgoing) wrapper function, the
+//! builder accepts optional wrapper functions providing
+//! - a [`Heuristic`] (see [`NodifyiedBuilder::with_heuristic()`]),
+//! - a state key used to compare and hash the nodes instead of their whole
+//!   state (see [`NodifyiedBuilder::with_key()`]), and
+//! - a [`Canonical`] representative (see
+//!   [`NodifyiedBuilder::with_canonical()`]).
 //!
 //! See the documentation of the entities described just above for more
 //! information.
 
-use super::{Node, ToValue};
+use super::{Canonical, Heuristic, Node, ToValue, Weighted};
 use std::hash::{Hash, Hasher};
 
 /// The builder for [`Nodifyied`]
 ///
+/// The unset optional wrapper functions are `()`.
+///
 /// # Example
 ///
 /// You can consult the complete version of this example at
@@ -24,8 +37,11 @@ use std::hash::{Hash, Hasher};
 #[doc = include_str!("../examples/simple_nodifyied.rs")]
 /// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
-pub struct NodifyiedBuilder<F> {
+pub struct NodifyiedBuilder<F, H = (), K = (), Z = ()> {
     outgoing_wrapper: F,
+    heuristic_wrapper: H,
+    key_wrapper: K,
+    canonical_wrapper: Z,
 }
 
 impl<F> NodifyiedBuilder<F> {
@@ -39,9 +55,16 @@ impl<F> NodifyiedBuilder<F> {
     ///
     /// A new [`NodifyiedBuilder`] initialized with the given closure.
     pub fn new(outgoing_wrapper: F) -> Self {
-        Self { outgoing_wrapper }
+        Self {
+            outgoing_wrapper,
+            heuristic_wrapper: (),
+            key_wrapper: (),
+            canonical_wrapper: (),
+        }
     }
+}
 
+impl<F, H, K, Z> NodifyiedBuilder<F, H, K, Z> {
     /// Build the associated [`Nodifyied`] node
     ///
     /// # Arguments
@@ -51,10 +74,32 @@ impl<F> NodifyiedBuilder<F> {
     /// # Return
     ///
     /// The built associated [`Nodifyied`] node
-    pub fn build<C>(&self, current: C) -> Nodifyied<'_, C, F> {
+    pub fn build<C>(&self, current: C) -> Nodifyied<'_, C, F, H, K, Z> {
         Nodifyied {
             current,
-            outgoing_wrapper: &self.outgoing_wrapper,
+            builder: self,
+        }
+    }
+
+    /// Build the associated [`OwnedNodifyied`] node
+    ///
+    /// Unlike [`.build()`](Self::build), the built node owns a copy of the
+    /// wrapper functions and does not borrow the builder.
+    ///
+    /// # Arguments
+    ///
+    /// - `current` - The value of the first node
+    ///
+    /// # Return
+    ///
+    /// The built associated [`OwnedNodifyied`] node
+    pub fn build_owned<C>(&self, current: C) -> OwnedNodifyied<C, F, H, K, Z>
+    where
+        Self: Clone,
+    {
+        OwnedNodifyied {
+            current,
+            builder: self.clone(),
         }
     }
 
@@ -71,38 +116,195 @@ impl<F> NodifyiedBuilder<F> {
         self.outgoing_wrapper = outgoing_wrapper;
         self
     }
+
+    /// Consumme the current builder and create another with the same wrapper
+    /// functions as before except for the [`Heuristic`] one which is set to
+    /// `heuristic_wrapper`
+    ///
+    /// The built nodes implement [`Heuristic`] by calling `heuristic_wrapper`
+    /// on their current state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    /// use std::iter::once;
+    ///
+    /// let builder = NodifyiedBuilder::new(|i: u32| once(i + 1)).with_heuristic(|&i: &u32| 42 - i);
+    ///
+    /// assert_eq!(builder.build(40).heuristic(), 2);
+    /// ```
+    pub fn with_heuristic<G>(self, heuristic_wrapper: G) -> NodifyiedBuilder<F, G, K, Z> {
+        NodifyiedBuilder {
+            outgoing_wrapper: self.outgoing_wrapper,
+            heuristic_wrapper,
+            key_wrapper: self.key_wrapper,
+            canonical_wrapper: self.canonical_wrapper,
+        }
+    }
+
+    /// Consumme the current builder and create another with the same wrapper
+    /// functions as before except for the state key one which is set to
+    /// `key_wrapper`
+    ///
+    /// The built nodes are compared and hashed through the key returned by
+    /// `key_wrapper` instead of their whole current state. Hence, the states
+    /// sharing the same key are explored only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// // The second component only records the number of steps
+    /// let builder = NodifyiedBuilder::new(|(i, steps): (u32, u32)| {
+    ///     [((i + 1) % 10, steps + 1), (i * 2 % 10, steps + 1)].into_iter()
+    /// })
+    /// .with_key(|&(i, _): &(u32, u32)| i);
+    ///
+    /// assert!(builder.build((3, 0)) == builder.build((3, 7)));
+    ///
+    /// let reachable = builder.build((1, 0)).to_process::<DFS<_>>().reachable();
+    /// assert_eq!(reachable.len(), 10);
+    /// ```
+    pub fn with_key<L>(self, key_wrapper: L) -> NodifyiedBuilder<F, H, L, Z> {
+        NodifyiedBuilder {
+            outgoing_wrapper: self.outgoing_wrapper,
+            heuristic_wrapper: self.heuristic_wrapper,
+            key_wrapper,
+            canonical_wrapper: self.canonical_wrapper,
+        }
+    }
+
+    /// Consumme the current builder and create another with the same wrapper
+    /// functions as before except for the [`Canonical`] one which is set to
+    /// `canonical_wrapper`
+    ///
+    /// The built nodes implement [`Canonical`] by calling `canonical_wrapper`
+    /// on their current state. Hence, the symmetric states are merged when
+    /// exploring from a [`.canonicalized()`](Canonical::canonicalized) node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// // A walk on the integers which is symmetric with respect to 0
+    /// let walk = |i: i32| [i - 1, i + 1].into_iter().filter(|i| i.abs() <= 5);
+    /// let builder = NodifyiedBuilder::new(walk).with_canonical(i32::abs);
+    ///
+    /// let reachable = builder
+    ///     .build(0)
+    ///     .canonicalized()
+    ///     .to_process::<DFS<_>>()
+    ///     .reachable();
+    ///
+    /// assert_eq!(reachable.len(), 6);
+    /// ```
+    pub fn with_canonical<Y>(self, canonical_wrapper: Y) -> NodifyiedBuilder<F, H, K, Y> {
+        NodifyiedBuilder {
+            outgoing_wrapper: self.outgoing_wrapper,
+            heuristic_wrapper: self.heuristic_wrapper,
+            key_wrapper: self.key_wrapper,
+            canonical_wrapper,
+        }
+    }
 }
 
-/// A _nodifyied_ node
-#[derive(Clone, Copy, Debug)]
-pub struct Nodifyied<'a, C, F> {
-    current: C,
-    outgoing_wrapper: &'a F,
+impl<F> NodifyiedBuilder<WeightedOutgoing<F>> {
+    /// Create a new weighted [`NodifyiedBuilder`]
+    ///
+    /// The built [`Nodifyied`] nodes implement [`Weighted`] in addition to
+    /// [`Node`].
+    ///
+    /// # Arguments
+    ///
+    /// - `weighted_outgoing_wrapper` - The
+    ///   [`.weighted_outgoing()`](Weighted::weighted_outgoing) wrapper function
+    ///   used
+    ///
+    /// # Return
+    ///
+    /// A new weighted [`NodifyiedBuilder`] initialized with the given closure.
+    ///
+    /// # Example
+    ///
+    /// A more complete example solving the Knapsack problem is available at
+    /// `examples/weighted_nodifyied.rs`.
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// let builder = NodifyiedBuilder::weighted(|i: u32| [(1, i + 1), (3, i * 2)].into_iter());
+    ///
+    /// let outgoing = builder
+    ///     .build(3)
+    ///     .weighted_outgoing()
+    ///     .map(|(weight, node)| (weight, node.to_value()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(outgoing, [(1, 4), (3, 6)]);
+    /// ```
+    pub fn weighted(weighted_outgoing_wrapper: F) -> Self {
+        Self::new(WeightedOutgoing(weighted_outgoing_wrapper))
+    }
 }
 
-/// [`PartialEq`] implementation for [`Nodifyied`]
-///
-/// The `current` state of the node is transparently compared with.
-impl<C: PartialEq, F> PartialEq for Nodifyied<'_, C, F> {
-    fn eq(&self, other: &Self) -> bool {
-        self.current.eq(&other.current)
+impl<F, H, K, Z> NodifyiedBuilder<WeightedOutgoing<F>, H, K, Z> {
+    /// Change the value of the
+    /// [`.weighted_outgoing()`](Weighted::weighted_outgoing) wrapper function
+    ///
+    /// # Arguments
+    ///
+    /// - `weighted_outgoing_wrapper` - The new value of the
+    ///   [`.weighted_outgoing()`](Weighted::weighted_outgoing) wrapper function
+    ///
+    /// # Return
+    ///
+    /// The altered [`NodifyiedBuilder`]
+    pub fn with_weighted_outgoing(&mut self, weighted_outgoing_wrapper: F) -> &mut Self {
+        self.with_outgoing(WeightedOutgoing(weighted_outgoing_wrapper))
     }
 }
 
-/// [`Eq`] implementation for [`Nodifyied`]
+/// A [`.weighted_outgoing()`](Weighted::weighted_outgoing) wrapper function
 ///
-/// The `current` state of the node is transparently compared with.
-impl<C: Eq, F> Eq for Nodifyied<'_, C, F> {}
+/// It is created by [`NodifyiedBuilder::weighted()`] and wraps a function-like
+/// returning the weighted outgoing states of the current one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WeightedOutgoing<F>(F);
+
+impl<F> WeightedOutgoing<F> {
+    /// Get the wrapped function-like
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+/// A _nodifyied_ node
+#[derive(Debug)]
+pub struct Nodifyied<'a, C, F, H = (), K = (), Z = ()> {
+    current: C,
+    builder: &'a NodifyiedBuilder<F, H, K, Z>,
+}
 
-/// [`Hash`] implementation for [`Nodifyied`]
+/// [`Clone`] implementation for [`Nodifyied`]
 ///
-/// The `current` state of the node is transparently hashed.
-impl<C: Hash, F> Hash for Nodifyied<'_, C, F> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.current.hash(state);
+/// Only the `current` state of the node has to be cloned.
+impl<C: Clone, F, H, K, Z> Clone for Nodifyied<'_, C, F, H, K, Z> {
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current.clone(),
+            builder: self.builder,
+        }
     }
 }
 
+/// [`Copy`] implementation for [`Nodifyied`]
+///
+/// Only the `current` state of the node has to be [`Copy`].
+impl<C: Copy, F, H, K, Z> Copy for Nodifyied<'_, C, F, H, K, Z> {}
+
 /// [`ToValue`] implementation for [`Nodifyied`]
 ///
 /// This implementation allows casting to the underlying type.
@@ -119,25 +321,218 @@ impl<C: Hash, F> Hash for Nodifyied<'_, C, F> {
 /// In the above example, you can see that
 /// [`.contains()`](crate::process::Contains::contains) takes a `FiboNode` and
 /// not a [`Nodifyied`].
-impl<C, F> ToValue<C> for Nodifyied<'_, C, F> {
+impl<C, F, H, K, Z> ToValue<C> for Nodifyied<'_, C, F, H, K, Z> {
     fn to_value(self) -> C {
         self.current
     }
 }
 
-/// [`Node`] implementation for [`Nodifyied`]
-///
-/// The function-like `outgoing_wrapper` is used to generate the outgoing nodes
-/// using the current state.
-impl<C, F, R> Node for Nodifyied<'_, C, F>
-where
-    F: Fn(C) -> R,
-    R: Iterator<Item = C>,
-{
-    fn outgoing(self) -> impl Iterator<Item = Self> {
-        (self.outgoing_wrapper)(self.current).map(move |current| Self {
-            current,
-            outgoing_wrapper: self.outgoing_wrapper,
-        })
+/// An owned _nodifyied_ node
+///
+/// Unlike [`Nodifyied`], this node owns its wrapper functions. Hence, it does
+/// not borrow any [`NodifyiedBuilder`] and is `'static`, [`Send`] and [`Sync`]
+/// as soon as the current state and the wrapper functions are. As the
+/// [`Process`es](crate::process::Process) require [`Copy`] nodes, the wrapper
+/// functions should be [`Copy`] as well, e.g. function pointers or closures
+/// capturing `'static` references only.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+/// use std::{iter::once, thread};
+///
+/// let root = NodifyiedBuilder::new(|i: u32| once(i + 1)).build_owned(0);
+///
+/// let found = thread::spawn(move || root.to_process::<DFS<_>>().contains(|i: u32| i == 42));
+///
+/// assert!(found.join().unwrap());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OwnedNodifyied<C, F, H = (), K = (), Z = ()> {
+    current: C,
+    builder: NodifyiedBuilder<F, H, K, Z>,
+}
+
+/// [`ToValue`] implementation for [`OwnedNodifyied`]
+///
+/// This implementation allows casting to the underlying type.
+impl<C, F, H, K, Z> ToValue<C> for OwnedNodifyied<C, F, H, K, Z> {
+    fn to_value(self) -> C {
+        self.current
     }
 }
+
+/// Implement the traits shared by [`Nodifyied`] and [`OwnedNodifyied`]
+///
+/// `$builder` is the type of their `builder` field without its generic
+/// parameters.
+macro_rules! impl_nodifyied {
+    ($nodifyied:ident $(<$lifetime:lifetime>)?, [$($builder:tt)+]) => {
+        #[doc = concat!("[`PartialEq`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The `current` state of the node is transparently compared with.
+        impl<$($lifetime,)? C: PartialEq, F, H, Z> PartialEq
+            for $nodifyied<$($lifetime,)? C, F, H, (), Z>
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.current.eq(&other.current)
+            }
+        }
+
+        #[doc = concat!(
+            "[`PartialEq`] implementation for [`",
+            stringify!($nodifyied),
+            "`] with a state key",
+        )]
+        ///
+        /// The keys of the `current` states are compared with.
+        impl<$($lifetime,)? C, F, H, K, Q, Z> PartialEq for $nodifyied<$($lifetime,)? C, F, H, K, Z>
+        where
+            K: Fn(&C) -> Q,
+            Q: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                let key = (self.builder.key_wrapper)(&self.current);
+                key.eq(&(other.builder.key_wrapper)(&other.current))
+            }
+        }
+
+        #[doc = concat!("[`Eq`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The `current` state of the node is transparently compared with.
+        impl<$($lifetime,)? C: Eq, F, H, Z> Eq for $nodifyied<$($lifetime,)? C, F, H, (), Z> {}
+
+        #[doc = concat!(
+            "[`Eq`] implementation for [`",
+            stringify!($nodifyied),
+            "`] with a state key",
+        )]
+        ///
+        /// The keys of the `current` states are compared with.
+        impl<$($lifetime,)? C, F, H, K, Q, Z> Eq for $nodifyied<$($lifetime,)? C, F, H, K, Z>
+        where
+            K: Fn(&C) -> Q,
+            Q: Eq,
+        {
+        }
+
+        #[doc = concat!("[`Hash`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The `current` state of the node is transparently hashed.
+        impl<$($lifetime,)? C: Hash, F, H, Z> Hash for $nodifyied<$($lifetime,)? C, F, H, (), Z> {
+            fn hash<S: Hasher>(&self, state: &mut S) {
+                self.current.hash(state);
+            }
+        }
+
+        #[doc = concat!(
+            "[`Hash`] implementation for [`",
+            stringify!($nodifyied),
+            "`] with a state key",
+        )]
+        ///
+        /// The key of the `current` state is hashed.
+        impl<$($lifetime,)? C, F, H, K, Q, Z> Hash for $nodifyied<$($lifetime,)? C, F, H, K, Z>
+        where
+            K: Fn(&C) -> Q,
+            Q: Hash,
+        {
+            fn hash<S: Hasher>(&self, state: &mut S) {
+                (self.builder.key_wrapper)(&self.current).hash(state);
+            }
+        }
+
+        #[doc = concat!("[`Node`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The function-like `outgoing_wrapper` is used to generate the outgoing nodes
+        /// using the current state.
+        impl<$($lifetime,)? C, F, H, K, Z, R> Node for $nodifyied<$($lifetime,)? C, F, H, K, Z>
+        where
+            $($builder)+<F, H, K, Z>: Clone,
+            F: Fn(C) -> R,
+            R: Iterator<Item = C>,
+        {
+            fn outgoing(self) -> impl Iterator<Item = Self> {
+                (self.builder.outgoing_wrapper)(self.current).map(move |current| Self {
+                    current,
+                    builder: Clone::clone(&self.builder),
+                })
+            }
+        }
+
+        #[doc = concat!("[`Weighted`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The function-like wrapped by [`WeightedOutgoing`] is used to generate the
+        /// weighted outgoing nodes using the current state.
+        impl<$($lifetime,)? C, F, H, K, Z, R, W> Weighted
+            for $nodifyied<$($lifetime,)? C, WeightedOutgoing<F>, H, K, Z>
+        where
+            $($builder)+<WeightedOutgoing<F>, H, K, Z>: Clone,
+            F: Fn(C) -> R,
+            R: Iterator<Item = (W, C)>,
+        {
+            type Weight = W;
+
+            fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+                (self.builder.outgoing_wrapper.0)(self.current).map(move |(weight, current)| {
+                    let node = Self {
+                        current,
+                        builder: Clone::clone(&self.builder),
+                    };
+
+                    (weight, node)
+                })
+            }
+        }
+
+        #[doc = concat!("[`Node`] implementation for weighted [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The weights of the weighted outgoing nodes are discarded.
+        impl<$($lifetime,)? C, F, H, K, Z, R, W> Node
+            for $nodifyied<$($lifetime,)? C, WeightedOutgoing<F>, H, K, Z>
+        where
+            $($builder)+<WeightedOutgoing<F>, H, K, Z>: Clone,
+            F: Fn(C) -> R,
+            R: Iterator<Item = (W, C)>,
+        {
+            fn outgoing(self) -> impl Iterator<Item = Self> {
+                self.weighted_outgoing().map(|(_, node)| node)
+            }
+        }
+
+        #[doc = concat!("[`Heuristic`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The function-like `heuristic_wrapper` estimates the distance from the
+        /// current state.
+        impl<$($lifetime,)? C, F, H, K, Z, T> Heuristic for $nodifyied<$($lifetime,)? C, F, H, K, Z>
+        where
+            H: Fn(&C) -> T,
+        {
+            type Cost = T;
+
+            fn heuristic(&self) -> Self::Cost {
+                (self.builder.heuristic_wrapper)(&self.current)
+            }
+        }
+
+        #[doc = concat!("[`Canonical`] implementation for [`", stringify!($nodifyied), "`]")]
+        ///
+        /// The function-like `canonical_wrapper` maps the current state to its
+        /// canonical representative.
+        impl<$($lifetime,)? C, F, H, K, Z> Canonical for $nodifyied<$($lifetime,)? C, F, H, K, Z>
+        where
+            Z: Fn(C) -> C,
+        {
+            fn canonical(self) -> Self {
+                Self {
+                    current: (self.builder.canonical_wrapper)(self.current),
+                    builder: self.builder,
+                }
+            }
+        }
+    };
+}
+
+impl_nodifyied!(Nodifyied<'a>, [&'a NodifyiedBuilder]);
+impl_nodifyied!(OwnedNodifyied, [NodifyiedBuilder]);
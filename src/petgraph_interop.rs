@@ -0,0 +1,212 @@
+//! The [petgraph](https://github.com/petgraph/petgraph) interoperability
+//!
+//! # Description
+//!
+//! This module contains
+//! - [`PetNode`] which exposes a node of any petgraph graph (e.g.
+//!   [`Graph`](petgraph::Graph) or
+//!   [`StableGraph`](petgraph::stable_graph::StableGraph)) as a
+//!   [node](Node), and
+//! - [`.into_digraph()`](Materialized::into_digraph) which converts a
+//!   [`Materialized`] graph into a [`DiGraph`] in order to use the petgraph
+//!   algorithms on it.
+//!
+//! See the documentation of the entities described just above for more
+//! information.
+
+use super::{Node, ToValue, Weighted, graph::Materialized};
+
+use petgraph::{
+    graph::{DiGraph, NodeIndex},
+    visit::{EdgeRef, GraphBase, IntoEdges, IntoNeighbors},
+};
+
+use std::hash::{Hash, Hasher};
+
+/// A [node](Node) of a petgraph graph
+///
+/// A [`PetNode`] is made of a reference to a petgraph graph and of a node
+/// index. The outgoing nodes are the neighbors of the node and the weight of
+/// an edge is its petgraph weight.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+///
+/// graph.add_edge(a, b, 2);
+/// graph.add_edge(b, c, 3);
+///
+/// let found = PetNode::new(&graph, a)
+///     .to_process::<DFS<_>>()
+///     .find_path(|index: NodeIndex| index == c)
+///     .unwrap();
+///
+/// assert_eq!(found.len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct PetNode<G: GraphBase> {
+    graph: G,
+    index: G::NodeId,
+}
+
+impl<G: GraphBase> PetNode<G> {
+    /// Create a new [`PetNode`]
+    ///
+    /// # Arguments
+    ///
+    /// - `graph` - The reference to the petgraph graph
+    /// - `index` - The index of the node in `graph`
+    ///
+    /// # Return
+    ///
+    /// A new [`PetNode`] referring to the node `index` of `graph`.
+    pub fn new(graph: G, index: G::NodeId) -> Self {
+        Self { graph, index }
+    }
+
+    /// Get the index of the current node
+    pub fn index(&self) -> G::NodeId {
+        self.index
+    }
+
+    /// Get the petgraph graph containing the current node
+    pub fn graph(&self) -> G
+    where
+        G: Copy,
+    {
+        self.graph
+    }
+}
+
+impl<G: Copy + GraphBase> Clone for PetNode<G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G: Copy + GraphBase> Copy for PetNode<G> {}
+
+/// [`PartialEq`] implementation for [`PetNode`]
+///
+/// Only the indices are compared with.
+impl<G: GraphBase> PartialEq for PetNode<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+/// [`Eq`] implementation for [`PetNode`]
+///
+/// Only the indices are compared with.
+impl<G: GraphBase> Eq for PetNode<G> {}
+
+/// [`Hash`] implementation for [`PetNode`]
+///
+/// Only the index is hashed.
+impl<G> Hash for PetNode<G>
+where
+    G: GraphBase,
+    G::NodeId: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+/// [`ToValue`] implementation for [`PetNode`]
+///
+/// This implementation allows casting to the node index.
+impl<G, Ix> ToValue<NodeIndex<Ix>> for PetNode<G>
+where
+    G: GraphBase<NodeId = NodeIndex<Ix>>,
+{
+    fn to_value(self) -> NodeIndex<Ix> {
+        self.index
+    }
+}
+
+/// [`Node`] implementation for [`PetNode`]
+///
+/// The outgoing nodes are the petgraph neighbors.
+impl<G: IntoNeighbors> Node for PetNode<G> {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let graph = self.graph;
+
+        graph
+            .neighbors(self.index)
+            .map(move |index| Self { graph, index })
+    }
+}
+
+/// [`Weighted`] implementation for [`PetNode`]
+///
+/// The weights are the petgraph edge weights.
+impl<G> Weighted for PetNode<G>
+where
+    G: IntoEdges,
+    G::EdgeWeight: Copy,
+{
+    type Weight = G::EdgeWeight;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let graph = self.graph;
+
+        graph.edges(self.index).map(move |edge| {
+            let node = Self {
+                graph,
+                index: edge.target(),
+            };
+
+            (*edge.weight(), node)
+        })
+    }
+}
+
+impl<N, W> Materialized<N, W> {
+    /// Convert the current [`Materialized`] graph into a [`DiGraph`]
+    ///
+    /// The petgraph node indices are the ones of the [`Materialized`] graph,
+    /// i.e. the root node is the one of index `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    /// use petgraph::algo::tarjan_scc;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         [Number((self.0 + 1) % 10), Number(self.0 * 2 % 10)].into_iter()
+    ///     }
+    /// }
+    ///
+    /// let graph = Number(1).materialize().into_digraph();
+    ///
+    /// assert_eq!(graph.node_count(), 10);
+    /// assert_eq!(tarjan_scc(&graph).len(), 1);
+    /// ```
+    pub fn into_digraph(self) -> DiGraph<N, W> {
+        let (graph, nodes) = self.into_parts();
+        let mut digraph = DiGraph::with_capacity(graph.node_count(), graph.edge_count());
+
+        for node in nodes {
+            digraph.add_node(node);
+        }
+
+        for (from, to, weight) in graph.into_edges() {
+            digraph.add_edge(NodeIndex::new(from), NodeIndex::new(to), weight);
+        }
+
+        digraph
+    }
+}
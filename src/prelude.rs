@@ -4,6 +4,14 @@
 //! include `use nodify::prelude::*` and have easy access to the various traits and methods you
 //! will need.
 
+pub use crate::adapter::*;
+pub use crate::export::*;
+pub use crate::graph::*;
 pub use crate::nodifyied::*;
+
+#[cfg(feature = "petgraph")]
+pub use crate::petgraph_interop::*;
+
 pub use crate::process::*;
+pub use crate::product::*;
 pub use crate::*;
@@ -5,6 +5,15 @@
 pub mod dfs;
 pub use dfs::DFS;
 
+#[cfg(feature = "async")]
+pub mod async_search;
+
+pub mod bidirectional;
+pub use bidirectional::{Bidirectional, WeightedBidirectional};
+
+#[cfg(feature = "async")]
+pub use async_search::{AsyncBFS, AsyncDFS};
+
 #[cfg(feature = "rayon")]
 pub mod parallel_dfs;
 
@@ -66,6 +75,214 @@ where
     /// [`.find_any()`](FindAny::find_any) is a better choice.
     ///
     /// For a weighted graph, the _first_ element is the one with the lowest
-    /// distance from the start node.
+    /// distance from the start node. For a depth-first search, it is the
+    /// first one of the pre-order visiting the outgoing nodes in their
+    /// iteration order.
     fn find_first(&self, pred: P) -> Option<Self::Node>;
 }
+
+/// A [`Process`] allowing to find the path leading to any [`super::Node`]
+/// verifying a given predicate
+pub trait FindPath<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// Search for some item that matches with the given predicate and return
+    /// the path leading to it
+    ///
+    /// The returned path starts with the start node and ends with the found
+    /// item. The found item is chosen in the same way as in
+    /// [`.find_any()`](FindAny::find_any) or
+    /// [`.find_first()`](FindFirst::find_first) according to the
+    /// [`Process`].
+    fn find_path(&self, pred: P) -> Option<Vec<Self::Node>>;
+}
+
+/// A [`Process`] allowing to find the labels of the edges leading to any
+/// [`super::Node`] verifying a given predicate
+///
+/// The labels are given by [`super::Labeled`] or [`super::WeightedLabeled`].
+pub trait FindLabels<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// The type used as label
+    type Label;
+
+    /// Search for some item that matches with the given predicate and return
+    /// the labels of the edges leading to it
+    ///
+    /// The search only follows the labeled edges and records the label of
+    /// every edge with its predecessor. Hence, the returned labels are the
+    /// ones of the followed path, which is found as by
+    /// [`.find_path()`](FindPath::find_path) whenever the labeled edges are
+    /// the same as the unlabeled ones.
+    fn find_labels(&self, pred: P) -> Option<Vec<Self::Label>>;
+}
+
+/// A [`Process`] allowing to collect all the [`super::Node`]s reachable from
+/// the start node
+pub trait Reachable: Process {
+    /// Collect all the [`super::Node`]s reachable from the start node
+    ///
+    /// The start node is included in the returned nodes.
+    fn reachable(&self) -> Vec<Self::Node>;
+}
+
+/// A fallible [`Process`] allowing to check a graph contains any
+/// [`super::Node`] verifying a given predicate
+///
+/// This [`Process`] is the counterpart of [`Contains`] for the graphs built
+/// using [`super::TryNode`] or [`super::TryWeighted`].
+pub trait TryContains<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Check if a graph contains any [`super::Node`] verifying the given
+    /// predicate _pred_
+    ///
+    /// The exploration stops at the first error raised while generating the
+    /// outgoing nodes and this error is returned.
+    fn try_contains(&self, pred: P) -> Result<bool, Self::Error>;
+}
+
+/// A fallible [`Process`] allowing to find any [`super::Node`] verifying a
+/// given predicate
+///
+/// This [`Process`] is the counterpart of [`FindAny`] for the graphs built
+/// using [`super::TryNode`] or [`super::TryWeighted`].
+pub trait TryFindAny<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Search for some item that matches with the given predicate
+    ///
+    /// See [`.find_any()`](FindAny::find_any) for more information. The
+    /// exploration stops at the first error raised while generating the
+    /// outgoing nodes and this error is returned.
+    fn try_find_any(&self, pred: P) -> Result<Option<Self::Node>, Self::Error>;
+}
+
+/// A fallible [`Process`] allowing to find the first [`super::Node`] verifying
+/// a given predicate
+///
+/// This [`Process`] is the counterpart of [`FindFirst`] for the graphs built
+/// using [`super::TryNode`] or [`super::TryWeighted`].
+pub trait TryFindFirst<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Search for the sequentially **first** item that matches the given
+    /// predicate and return it
+    ///
+    /// See [`.find_first()`](FindFirst::find_first) for more information. The
+    /// exploration stops at the first error raised while generating the
+    /// outgoing nodes and this error is returned.
+    fn try_find_first(&self, pred: P) -> Result<Option<Self::Node>, Self::Error>;
+}
+
+/// An asynchronous [`Process`] allowing to check a graph contains any
+/// [`super::AsyncNode`] verifying a given predicate
+#[cfg(feature = "async")]
+pub trait AsyncContains<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// Check if a graph contains any [`super::AsyncNode`] verifying the given
+    /// predicate _pred_
+    fn contains(&self, pred: P) -> impl Future<Output = bool>;
+}
+
+/// An asynchronous [`Process`] allowing to find any [`super::AsyncNode`]
+/// verifying a given predicate
+#[cfg(feature = "async")]
+pub trait AsyncFindAny<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// Search for some item that matches with the given predicate
+    ///
+    /// See [`.find_any()`](FindAny::find_any) for more information.
+    fn find_any(&self, pred: P) -> impl Future<Output = Option<Self::Node>>;
+}
+
+/// A fallible asynchronous [`Process`] allowing to check a graph contains any
+/// [`super::AsyncTryNode`] verifying a given predicate
+#[cfg(feature = "async")]
+pub trait AsyncTryContains<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Check if a graph contains any [`super::AsyncTryNode`] verifying the
+    /// given predicate _pred_
+    ///
+    /// The exploration stops at the first error raised while generating the
+    /// outgoing nodes and this error is returned.
+    fn try_contains(&self, pred: P) -> impl Future<Output = Result<bool, Self::Error>>;
+}
+
+/// A fallible asynchronous [`Process`] allowing to find any
+/// [`super::AsyncTryNode`] verifying a given predicate
+#[cfg(feature = "async")]
+pub trait AsyncTryFindAny<I, P>: Process
+where
+    P: Fn(I) -> bool,
+{
+    /// The error type raised while generating the outgoing nodes
+    type Error;
+
+    /// Search for some item that matches with the given predicate
+    ///
+    /// See [`.find_any()`](FindAny::find_any) for more information. The
+    /// exploration stops at the first error raised while generating the
+    /// outgoing nodes and this error is returned.
+    fn try_find_any(
+        &self,
+        pred: P,
+    ) -> impl Future<Output = Result<Option<Self::Node>, Self::Error>>;
+}
+
+/// Build the path leading to `node` using the given `parent` function
+///
+/// The `parent` function has to return [`None`] for the start node.
+pub(crate) fn path_to<N, F>(node: N, mut parent: F) -> Vec<N>
+where
+    N: Copy,
+    F: FnMut(N) -> Option<N>,
+{
+    let mut path = std::iter::successors(Some(node), |&node| parent(node)).collect::<Vec<_>>();
+    path.reverse();
+    path
+}
+
+/// Get the labels of the edges of the path leading to `node` using the given
+/// `parent` function
+///
+/// The `parent` function has to return the parent of its argument with the
+/// label of the edge linking them, [`None`] for the start node.
+pub(crate) fn labels_to<N, L, F>(mut node: N, mut parent: F) -> Vec<L>
+where
+    F: FnMut(N) -> Option<(N, L)>,
+{
+    let mut labels = Vec::default();
+
+    while let Some((parent, label)) = parent(node) {
+        labels.push(label);
+        node = parent;
+    }
+
+    labels.reverse();
+    labels
+}
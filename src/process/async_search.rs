@@ -0,0 +1,191 @@
+//! This module contains the implementation of [`AsyncDFS`] and [`AsyncBFS`]
+
+use super::{AsyncContains, AsyncFindAny, AsyncTryContains, AsyncTryFindAny, Process};
+use crate::{AsyncNode, AsyncTryNode, ToValue};
+use futures::{
+    FutureExt,
+    stream::{FuturesUnordered, StreamExt},
+};
+use std::{collections::VecDeque, convert::Infallible, hash::Hash};
+
+/// The default number of expansions kept in flight
+const DEFAULT_CONCURRENCY: usize = 16;
+
+/// An asynchronous [DFS](https://en.wikipedia.org/wiki/Depth-first_search) implementation of some processes
+///
+/// Up to [`.concurrency()`](AsyncDFS::concurrency) expansions are kept in
+/// flight. The most recently discovered nodes are expanded first.
+///
+/// In particular, the following [`Process`es](Process) are implemented:
+/// - [`AsyncContains`],
+/// - [`AsyncFindAny`],
+/// - [`AsyncTryContains`],
+/// - [`AsyncTryFindAny`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AsyncDFS<N> {
+    node: N,
+    concurrency: usize,
+}
+
+/// An asynchronous [BFS](https://en.wikipedia.org/wiki/Breadth-first_search) implementation of some processes
+///
+/// Up to [`.concurrency()`](AsyncBFS::concurrency) expansions are kept in
+/// flight. The least recently discovered nodes are expanded first.
+///
+/// In particular, the following [`Process`es](Process) are implemented:
+/// - [`AsyncContains`],
+/// - [`AsyncFindAny`],
+/// - [`AsyncTryContains`],
+/// - [`AsyncTryFindAny`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AsyncBFS<N> {
+    node: N,
+    concurrency: usize,
+}
+
+macro_rules! impl_async_search {
+    ($process:ident, $pop:ident) => {
+        impl<N> $process<N> {
+            /// Get the maximum number of expansions kept in flight
+            pub fn concurrency(&self) -> usize {
+                self.concurrency
+            }
+
+            /// Consumme the current instance and create another with the same
+            /// values as before except for the maximum number of expansions
+            /// kept in flight which is updated to `concurrency`.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `concurrency` is zero.
+            pub fn with_concurrency(self, concurrency: usize) -> Self {
+                assert!(concurrency > 0, "The concurrency must be positive");
+
+                Self {
+                    node: self.node,
+                    concurrency,
+                }
+            }
+        }
+
+        impl<N: Default> Default for $process<N> {
+            fn default() -> Self {
+                Self::from_node(N::default())
+            }
+        }
+
+        impl<N> Process for $process<N> {
+            type Node = N;
+
+            fn from_node(node: Self::Node) -> Self {
+                Self {
+                    node,
+                    concurrency: DEFAULT_CONCURRENCY,
+                }
+            }
+        }
+
+        impl<I, N, P> AsyncContains<I, P> for $process<N>
+        where
+            N: AsyncNode + Copy + Eq + Hash + ToValue<I>,
+            P: Fn(I) -> bool,
+        {
+            async fn contains(&self, pred: P) -> bool {
+                self.find_any(pred).await.is_some()
+            }
+        }
+
+        impl<I, N, P> AsyncFindAny<I, P> for $process<N>
+        where
+            N: AsyncNode + Copy + Eq + Hash + ToValue<I>,
+            P: Fn(I) -> bool,
+        {
+            async fn find_any(&self, pred: P) -> Option<Self::Node> {
+                let outgoing = |node: N| node.async_outgoing().map(Ok::<_, Infallible>);
+                let Ok(found) =
+                    search(self.node, self.concurrency, pred, outgoing, VecDeque::$pop).await;
+                found
+            }
+        }
+
+        impl<I, N, P> AsyncTryContains<I, P> for $process<N>
+        where
+            N: AsyncTryNode + Copy + Eq + Hash + ToValue<I>,
+            P: Fn(I) -> bool,
+        {
+            type Error = N::Error;
+
+            async fn try_contains(&self, pred: P) -> Result<bool, Self::Error> {
+                self.try_find_any(pred).await.map(|found| found.is_some())
+            }
+        }
+
+        impl<I, N, P> AsyncTryFindAny<I, P> for $process<N>
+        where
+            N: AsyncTryNode + Copy + Eq + Hash + ToValue<I>,
+            P: Fn(I) -> bool,
+        {
+            type Error = N::Error;
+
+            async fn try_find_any(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+                search(
+                    self.node,
+                    self.concurrency,
+                    pred,
+                    N::try_async_outgoing,
+                    VecDeque::$pop,
+                )
+                .await
+            }
+        }
+    };
+}
+
+impl_async_search!(AsyncDFS, pop_back);
+impl_async_search!(AsyncBFS, pop_front);
+
+/// Search for some node verifying `pred`
+///
+/// `outgoing` is used to generate the outgoing nodes and the first raised
+/// error stops the exploration. `pop` is used to choose the next node to
+/// expand among the discovered ones.
+async fn search<I, N, P, X, F, R, E>(
+    node: N,
+    concurrency: usize,
+    pred: P,
+    outgoing: X,
+    pop: fn(&mut VecDeque<N>) -> Option<N>,
+) -> Result<Option<N>, E>
+where
+    N: Copy + Eq + Hash + ToValue<I>,
+    P: Fn(I) -> bool,
+    X: Fn(N) -> F,
+    F: Future<Output = Result<R, E>>,
+    R: Iterator<Item = N>,
+{
+    type HashSet<K> = std::collections::HashSet<K, ahash::RandomState>;
+
+    let mut is_visited = HashSet::default();
+    let mut to_visit = VecDeque::from([node]);
+    let mut in_flight = FuturesUnordered::new();
+
+    loop {
+        while in_flight.len() < concurrency {
+            let Some(node) = pop(&mut to_visit) else {
+                break;
+            };
+
+            if pred(node.to_value()) {
+                return Ok(Some(node));
+            } else if is_visited.insert(node) {
+                in_flight.push(outgoing(node));
+            }
+        }
+
+        let Some(next) = in_flight.next().await else {
+            return Ok(None);
+        };
+
+        to_visit.extend(next?.filter(|node| !is_visited.contains(node)));
+    }
+}
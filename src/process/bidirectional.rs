@@ -0,0 +1,376 @@
+//! This module contains the implementation of [`Bidirectional`] and
+//! [`WeightedBidirectional`]
+
+use super::{Process, path_to};
+use crate::{Incoming, Node, Weighted, WeightedIncoming};
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+type HashMap<K, V> = std::collections::HashMap<K, V, ahash::RandomState>;
+
+/// A [bidirectional search](https://en.wikipedia.org/wiki/Bidirectional_search)
+/// implementation
+///
+/// Two [BFS](https://en.wikipedia.org/wiki/Breadth-first_search) are run
+/// alternatively: one from the start node using [`.outgoing()`](Node::outgoing)
+/// and one from the goal node using [`.incoming()`](Incoming::incoming). The
+/// smallest frontier is always expanded first so that both searches meet in the
+/// middle.
+///
+/// The goal node is set using [`.with_goal()`](Bidirectional::with_goal).
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Number(i32);
+///
+/// impl Node for Number {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         [Number(self.0 + 1), Number(self.0 - 1), Number(self.0 + 7)].into_iter()
+///     }
+/// }
+///
+/// impl Incoming for Number {
+///     fn incoming(self) -> impl Iterator<Item = Self> {
+///         [Number(self.0 - 1), Number(self.0 + 1), Number(self.0 - 7)].into_iter()
+///     }
+/// }
+///
+/// let path = Number(0)
+///     .to_process::<Bidirectional<_>>()
+///     .with_goal(Number(20))
+///     .find_path()
+///     .unwrap();
+///
+/// assert_eq!(path.len(), 5);
+/// assert_eq!(path.first(), Some(&Number(0)));
+/// assert_eq!(path.last(), Some(&Number(20)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bidirectional<N> {
+    start: N,
+    goal: N,
+}
+
+/// A weighted [bidirectional search](https://en.wikipedia.org/wiki/Bidirectional_search)
+/// implementation
+///
+/// Two [Dijkstra's algorithms](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm)
+/// are run alternatively: one from the start node using
+/// [`.weighted_outgoing()`](Weighted::weighted_outgoing) and one from the goal
+/// node using [`.weighted_incoming()`](WeightedIncoming::weighted_incoming).
+/// The search stops as soon as no shorter path can be found so that the
+/// returned path is a shortest one, as for
+/// [`FindFirst`](super::FindFirst).
+///
+/// The goal node is set using
+/// [`.with_goal()`](WeightedBidirectional::with_goal). The
+/// [`Default`] value of the weight type is used as zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WeightedBidirectional<N> {
+    start: N,
+    goal: N,
+}
+
+impl<N> Bidirectional<N> {
+    /// Create a new [`Bidirectional`] search between `start` and `goal`
+    pub fn new(start: N, goal: N) -> Self {
+        Self { start, goal }
+    }
+
+    /// Consumme the current [`Bidirectional`] instance and create another with
+    /// the same values as before except for the goal node which is updated to
+    /// `goal`.
+    pub fn with_goal(self, goal: N) -> Self {
+        Self {
+            start: self.start,
+            goal,
+        }
+    }
+}
+
+impl<N> WeightedBidirectional<N> {
+    /// Create a new [`WeightedBidirectional`] search between `start` and `goal`
+    pub fn new(start: N, goal: N) -> Self {
+        Self { start, goal }
+    }
+
+    /// Consumme the current [`WeightedBidirectional`] instance and create
+    /// another with the same values as before except for the goal node which
+    /// is updated to `goal`.
+    pub fn with_goal(self, goal: N) -> Self {
+        Self {
+            start: self.start,
+            goal,
+        }
+    }
+}
+
+/// [`Process`] implementation for [`Bidirectional`]
+///
+/// The goal node is initialized to the start node.
+impl<N: Copy> Process for Bidirectional<N> {
+    type Node = N;
+
+    fn from_node(node: Self::Node) -> Self {
+        Self::new(node, node)
+    }
+}
+
+/// [`Process`] implementation for [`WeightedBidirectional`]
+///
+/// The goal node is initialized to the start node.
+impl<N: Copy> Process for WeightedBidirectional<N> {
+    type Node = N;
+
+    fn from_node(node: Self::Node) -> Self {
+        Self::new(node, node)
+    }
+}
+
+impl<N> Bidirectional<N>
+where
+    N: Copy + Eq + Hash + Incoming + Node,
+{
+    /// Search for a path from the start node to the goal node
+    ///
+    /// The returned path starts with the start node, ends with the goal node
+    /// and has the lowest number of edges.
+    pub fn find_path(&self) -> Option<Vec<N>> {
+        if self.start == self.goal {
+            return Some(vec![self.start]);
+        }
+
+        let mut forward = Frontier::new(self.start);
+        let mut backward = Frontier::new(self.goal);
+
+        while !forward.to_visit.is_empty() && !backward.to_visit.is_empty() {
+            let meeting = if forward.to_visit.len() <= backward.to_visit.len() {
+                forward.expand(&backward, N::outgoing)
+            } else {
+                backward.expand(&forward, N::incoming)
+            };
+
+            if let Some(meeting) = meeting {
+                return Some(join(meeting, &forward.parents, &backward.parents));
+            }
+        }
+
+        None
+    }
+}
+
+/// A BFS frontier of [`Bidirectional`]
+struct Frontier<N> {
+    /// The nodes of the current level
+    to_visit: Vec<N>,
+
+    /// The parent and the depth of every discovered node
+    parents: HashMap<N, (Option<N>, usize)>,
+}
+
+impl<N: Copy + Eq + Hash> Frontier<N> {
+    fn new(node: N) -> Self {
+        Self {
+            to_visit: vec![node],
+            parents: HashMap::from_iter([(node, (None, 0))]),
+        }
+    }
+
+    /// Expand the current level and return the best meeting node with `other`
+    fn expand<X, R>(&mut self, other: &Self, outgoing: X) -> Option<N>
+    where
+        X: Fn(N) -> R,
+        R: Iterator<Item = N>,
+    {
+        let mut next_level = Vec::default();
+        let mut meeting = None;
+
+        for node in self.to_visit.drain(..) {
+            let depth = self.parents[&node].1 + 1;
+
+            for next in outgoing(node) {
+                if let Entry::Vacant(entry) = self.parents.entry(next) {
+                    entry.insert((Some(node), depth));
+                    next_level.push(next);
+
+                    if let Some(&(_, other_depth)) = other.parents.get(&next) {
+                        let length = depth + other_depth;
+
+                        if meeting.is_none_or(|(_, best)| length < best) {
+                            meeting = Some((next, length));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.to_visit = next_level;
+        meeting.map(|(node, _)| node)
+    }
+}
+
+impl<N, W> WeightedBidirectional<N>
+where
+    N: Copy + Eq + Hash + Weighted<Weight = W> + WeightedIncoming<Weight = W>,
+    W: Add<Output = W> + Copy + Default + Ord,
+{
+    /// Search for a shortest path from the start node to the goal node
+    ///
+    /// The returned path starts with the start node and ends with the goal
+    /// node. It is returned with its length.
+    pub fn find_path(&self) -> Option<(W, Vec<N>)> {
+        let mut forward = Dijkstra::new(self.start);
+        let mut backward = Dijkstra::new(self.goal);
+        let mut best = (self.start == self.goal).then_some((W::default(), self.start));
+
+        while let (Some(forward_top), Some(backward_top)) = (forward.top(), backward.top()) {
+            if best.is_some_and(|(length, _)| forward_top + backward_top >= length) {
+                break;
+            }
+
+            let meeting = if forward_top <= backward_top {
+                forward.settle(&backward, N::weighted_outgoing)
+            } else {
+                backward.settle(&forward, N::weighted_incoming)
+            };
+
+            if let Some(meeting) = meeting
+                && best.is_none_or(|(length, _)| meeting.0 < length)
+            {
+                best = Some(meeting);
+            }
+        }
+
+        best.map(|(length, meeting)| {
+            let path = join(meeting, &forward.parents, &backward.parents);
+            (length, path)
+        })
+    }
+}
+
+/// A Dijkstra's algorithm state of [`WeightedBidirectional`]
+struct Dijkstra<N, W> {
+    /// The discovered nodes sorted by increasing distance
+    to_visit: BinaryHeap<HeapEntry<W, N>>,
+
+    /// The parent and the distance of every discovered node
+    parents: HashMap<N, (Option<N>, W)>,
+}
+
+impl<N, W> Dijkstra<N, W>
+where
+    N: Copy + Eq + Hash,
+    W: Add<Output = W> + Copy + Default + Ord,
+{
+    fn new(node: N) -> Self {
+        Self {
+            to_visit: BinaryHeap::from([HeapEntry(W::default(), node)]),
+            parents: HashMap::from_iter([(node, (None, W::default()))]),
+        }
+    }
+
+    /// Get the distance of the next node to settle
+    fn top(&self) -> Option<W> {
+        self.to_visit.peek().map(|&HeapEntry(dist, _)| dist)
+    }
+
+    /// Settle the next node and return the best meeting node with `other`
+    /// with the length of the associated path
+    fn settle<X, R>(&mut self, other: &Self, outgoing: X) -> Option<(W, N)>
+    where
+        X: Fn(N) -> R,
+        R: Iterator<Item = (W, N)>,
+    {
+        let HeapEntry(dist, node) = self.to_visit.pop()?;
+
+        if self.parents[&node].1 < dist {
+            return None;
+        }
+
+        let mut meeting = None;
+
+        for (w, next) in outgoing(node) {
+            let new_dist = dist + w;
+
+            let is_shorter = match self.parents.entry(next) {
+                Entry::Occupied(entry) if new_dist < entry.get().1 => {
+                    *entry.into_mut() = (Some(node), new_dist);
+                    true
+                }
+
+                Entry::Occupied(_) => false,
+
+                Entry::Vacant(entry) => {
+                    entry.insert((Some(node), new_dist));
+                    true
+                }
+            };
+
+            if is_shorter {
+                self.to_visit.push(HeapEntry(new_dist, next));
+
+                if let Some(&(_, other_dist)) = other.parents.get(&next) {
+                    let length = new_dist + other_dist;
+
+                    if meeting.is_none_or(|(best, _)| length < best) {
+                        meeting = Some((length, next));
+                    }
+                }
+            }
+        }
+
+        meeting
+    }
+}
+
+/// An entry of [`BinaryHeap`] only ordered by its reversed distance
+struct HeapEntry<W, N>(W, N);
+
+impl<W: Ord, N> PartialEq for HeapEntry<W, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<W: Ord, N> Eq for HeapEntry<W, N> {}
+
+impl<W: Ord, N> PartialOrd for HeapEntry<W, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Ord, N> Ord for HeapEntry<W, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Join the forward path leading to `meeting` and the backward path leaving
+/// it
+fn join<N, T>(
+    meeting: N,
+    forward: &HashMap<N, (Option<N>, T)>,
+    backward: &HashMap<N, (Option<N>, T)>,
+) -> Vec<N>
+where
+    N: Copy + Eq + Hash,
+{
+    let mut path = path_to(meeting, |node| forward[&node].0);
+    let mut to_goal = path_to(meeting, |node| backward[&node].0);
+
+    to_goal.reverse();
+    to_goal.remove(0);
+    path.append(&mut to_goal);
+
+    path
+}
@@ -1,20 +1,26 @@
 //! This module contains the implementation of [`DeltaStepping`]
 
-use super::{Contains, FindAny, FindFirst, Process};
-use crate::{ToValue, Weighted};
-use num_traits::Unsigned;
+use super::{
+    Contains, FindAny, FindFirst, FindLabels, FindPath, Process, TryContains, TryFindAny,
+    TryFindFirst, labels_to, path_to,
+};
+
+use crate::{ToValue, TryWeighted, Weighted, WeightedLabeled};
+use num_traits::{Unsigned, Zero};
 use rayon::prelude::*;
 
 use std::{
-    cmp::min_by_key,
+    cmp,
     collections::LinkedList,
+    convert::Infallible,
     fmt::{Debug, Formatter},
     hash::Hash,
-    mem::swap,
+    sync::{Mutex, MutexGuard, PoisonError},
 };
 
 type HashMap<K, V> = dashmap::DashMap<K, V, ahash::RandomState>;
 type HashMultiMap<K, V> = HashMap<K, Vec<V>>;
+type HashSet<K> = dashmap::DashSet<K, ahash::RandomState>;
 
 /// A [delta stepping algorithm](https://en.wikipedia.org/wiki/Parallel_single-source_shortest_path_algorithm#Delta_stepping_algorithm)
 /// implementation of some [`Process`es](Process).
@@ -22,14 +28,90 @@ type HashMultiMap<K, V> = HashMap<K, Vec<V>>;
 /// In particular, the following [`Process`es](Process) are implemented:
 /// - [`Contains`],
 /// - [`FindAny`],
-/// - [`FindFirst`].
+/// - [`FindFirst`],
+/// - [`FindLabels`],
+/// - [`FindPath`],
+/// - [`TryContains`],
+/// - [`TryFindAny`],
+/// - [`TryFindFirst`].
+///
+/// The buckets are explored in parallel. Hence, the node found among several
+/// ones at the same distance and the predecessors along several paths of the
+/// same weight depend on the scheduling of the threads unless the
+/// [deterministic mode](Self::with_deterministic) is enabled.
+///
+/// Unless it is [given](Self::with_delta), the value of delta is chosen
+/// automatically. The first phase samples the weights of the outgoing edges of
+/// the base node and their median becomes delta. Between two phases, delta is
+/// then doubled when the phases explore fewer nodes than the available threads
+/// and halved when too many nodes are explored several times.
+///
+/// The settled nodes are kept between the searches of an instance. A search
+/// first looks for a node verifying its predicate among them and then resumes
+/// the exploration where the previous searches stopped. The searches sharing
+/// an instance are run one after the other and
+/// [`.reset()`](Self::reset) forgets all of them.
+///
+/// Since a search locks the instance until it returns, the predicate and the
+/// outgoing edges must not use the instance they are searched with: calling
+/// one of its searches, [`.delta()`](Self::delta) or [`.reset()`](Self::reset)
+/// from them deadlocks or panics, and [`.distances()`](Self::distances) only
+/// reflects the ongoing search.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Number(u32);
+///
+/// impl Weighted for Number {
+///     type Weight = u32;
+///
+///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+///         [(1, Number(self.0 + 1)), (4, Number(self.0 * 2))]
+///             .into_iter()
+///             .filter(|(_, Number(i))| *i <= 100)
+///     }
+/// }
+///
+/// impl Node for Number {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         self.weighted_outgoing().map(|(_, node)| node)
+///     }
+/// }
+///
+/// let process = DeltaStepping::from_node(Number(1));
+///
+/// let distance = |found: Option<Number>| {
+///     let found = found?;
+///
+///     process
+///         .distances()
+///         .into_iter()
+///         .find_map(|(node, distance)| (node == found).then_some(distance))
+/// };
+///
+/// assert_eq!(distance(process.find_first(|Number(i)| i == 40)), Some(16));
+/// assert_eq!(distance(process.find_first(|Number(i)| i == 3)), Some(2));
+/// assert_eq!(distance(process.find_first(|Number(i)| i == 97)), Some(22));
+/// assert_eq!(process.find_first(|Number(i)| i > 100), None);
+/// assert_eq!(distance(process.find_first(|Number(i)| i == 40)), Some(16));
+/// ```
 pub struct DeltaStepping<N, W> {
     base: N,
-    delta: W,
+    delta: Mutex<Delta<W>>,
+    order: Option<Order<N>>,
     buckets: HashMultiMap<W, N>,
     dists: HashMap<N, W>,
+    preds: HashMap<N, (N, usize)>,
+    settled: HashSet<N>,
 }
 
+/// The order breaking the ties of the deterministic mode
+type Order<N> = fn(&N, &N) -> cmp::Ordering;
+
 impl<N, W> DeltaStepping<N, W>
 where
     N: Send + Sync,
@@ -42,15 +124,275 @@ where
             .map(|r| *r.key())
             .min()
     }
+
+    /// Get the number of pending nodes
+    fn pending_count(&self) -> usize {
+        self.buckets.par_iter().map(|r| r.value().len()).sum()
+    }
+}
+
+impl<N, W> DeltaStepping<N, W>
+where
+    N: Copy + Eq + Hash + Send + Sync,
+    W: Copy + Eq + Hash + Ord + Send + Sync + Unsigned,
+{
+    /// Search for the first node verifying `pred`
+    ///
+    /// The settled nodes are searched first before resuming the exploration.
+    /// `outgoing` is used to generate the labeled outgoing edges and the first
+    /// raised error stops the exploration and resets the instance. `on_pred`
+    /// is called with a node and the label of the edge from its predecessor
+    /// whenever this predecessor is updated. `on_found` maps the found node
+    /// before the state is unlocked so that it can follow the predecessors.
+    fn search<I, P, X, R, L, E, F, G, T>(
+        &self,
+        pred: P,
+        outgoing: X,
+        on_pred: F,
+        on_found: G,
+    ) -> Result<Option<T>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool + Sync,
+        X: Fn(N) -> R + Sync,
+        R: Iterator<Item = Result<(W, L, N), E>>,
+        L: Send,
+        E: Send,
+        F: Fn(N, L) + Sync,
+        G: FnOnce(N) -> T,
+    {
+        let mut delta = self.lock();
+
+        let settled = self
+            .settled
+            .par_iter()
+            .map(|node| *node)
+            .filter(|&node| pred(node.to_value()))
+            .filter_map(|node| Some((*self.dists.get(&node)?, node)))
+            .reduce_with(|lhs, rhs| first_solved(lhs, rhs, self.order));
+
+        if let Some((_, node)) = settled {
+            return Ok(Some(on_found(node)));
+        }
+
+        let found = self.explore(&mut delta, &pred, &outgoing, &on_pred);
+
+        if found.is_err() {
+            self.clear(&mut delta);
+        }
+
+        found.map(|found| found.map(on_found))
+    }
+
+    /// Explore the buckets until a node verifying `pred` is settled
+    ///
+    /// Every bucket is completely explored so that the search can be resumed.
+    /// `delta` is adapted between the phases.
+    fn explore<I, P, X, R, L, E, F>(
+        &self,
+        delta: &mut Delta<W>,
+        pred: &P,
+        outgoing: &X,
+        on_pred: &F,
+    ) -> Result<Option<N>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool + Sync,
+        X: Fn(N) -> R + Sync,
+        R: Iterator<Item = Result<(W, L, N), E>>,
+        L: Send,
+        E: Send,
+        F: Fn(N, L) + Sync,
+    {
+        while let Some(first_index) = self.first_bucket_index() {
+            let mut explored = Explored::default();
+
+            while let Some((_, first_bucket)) = self.buckets.remove(&first_index) {
+                let mut to_append = first_bucket
+                    .into_par_iter()
+                    .try_fold(Explored::default, |mut explored, node| {
+                        let node = self.node(node, delta.value);
+                        let mut to_append = node.explore(pred, outgoing, on_pred)?;
+                        explored.append(&mut to_append, self.order);
+                        Ok(explored)
+                    })
+                    .try_reduce(Explored::default, |mut lhs, mut rhs| {
+                        lhs.append(&mut rhs, self.order);
+                        Ok(lhs)
+                    })?;
+
+                explored.append(&mut to_append, self.order);
+            }
+
+            let threads = rayon::current_num_threads();
+
+            if delta.adapt(&explored, self.pending_count(), threads) {
+                self.rebucket(delta.value);
+            }
+
+            explored.heavy_edges.into_par_iter().flatten().for_each(
+                |(new_dist, parent, label, node)| {
+                    self.node(node, delta.value)
+                        .relax(new_dist, parent, label, on_pred)
+                },
+            );
+
+            if let Some((_, node)) = explored.solved {
+                return Ok(Some(node));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Move the pending nodes to the buckets of `delta`
+    ///
+    /// The duplicated pending nodes are removed at the same time.
+    fn rebucket(&self, delta: W) {
+        let pending = HashSet::default();
+
+        self.buckets.par_iter_mut().for_each(|mut r| {
+            for node in r.value_mut().drain(..) {
+                pending.insert(node);
+            }
+        });
+
+        self.buckets.clear();
+
+        pending.into_par_iter().for_each(|node| {
+            if let Some(dist) = self.dists.get(&node).as_deref().copied() {
+                self.buckets.entry(dist / delta).or_default().push(node);
+            }
+        });
+    }
+}
+
+impl<N, W> DeltaStepping<N, W>
+where
+    N: Copy + Eq + Hash,
+    W: Eq + Hash + Unsigned,
+{
+    /// Get the current value of delta
+    ///
+    /// # Return
+    ///
+    /// The value given to [`.with_delta()`](Self::with_delta) if any. The
+    /// automatic value otherwise, which is zero until the first search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Weighted for Number {
+    ///     type Weight = u32;
+    ///
+    ///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+    ///         [(10, Number(self.0 + 1)), (30, Number(self.0 + 3)), (50, Number(self.0 + 7))]
+    ///             .into_iter()
+    ///             .filter(|(_, Number(i))| *i < 20)
+    ///     }
+    /// }
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         self.weighted_outgoing().map(|(_, node)| node)
+    ///     }
+    /// }
+    ///
+    /// let process = DeltaStepping::from_node(Number(0));
+    /// assert_eq!(process.delta(), 0);
+    ///
+    /// assert!(process.contains(|Number(i)| i == 19));
+    /// assert!(process.delta() > 0);
+    ///
+    /// let process = process.with_delta(20);
+    /// assert!(process.contains(|Number(i)| i == 19));
+    /// assert_eq!(process.delta(), 20);
+    /// ```
+    pub fn delta(&self) -> W
+    where
+        W: Copy,
+    {
+        self.lock().value
+    }
+
+    /// Forget every previous search
+    ///
+    /// The next search starts from the base node as a new instance would.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Weighted for Number {
+    ///     type Weight = u32;
+    ///
+    ///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+    ///         [(1, Number((self.0 + 1) % 10))].into_iter()
+    ///     }
+    /// }
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         self.weighted_outgoing().map(|(_, node)| node)
+    ///     }
+    /// }
+    ///
+    /// let process = DeltaStepping::from_node(Number(0)).with_delta(1);
+    /// assert!(!process.contains(|_: Number| false));
+    /// assert_eq!(process.distances().len(), 10);
+    ///
+    /// process.reset();
+    /// assert_eq!(process.distances(), [(Number(0), 0)]);
+    /// ```
+    pub fn reset(&self) {
+        self.clear(&mut self.lock());
+    }
+
+    /// Lock the state shared by the searches
+    ///
+    /// A state left by a panicking search is cleared.
+    fn lock(&self) -> MutexGuard<'_, Delta<W>> {
+        self.delta.lock().unwrap_or_else(|poisoned| {
+            let mut delta = poisoned.into_inner();
+            self.clear(&mut delta);
+            self.delta.clear_poison();
+            delta
+        })
+    }
+
+    /// Clear the state shared by the searches whose `delta` is locked
+    fn clear(&self, delta: &mut Delta<W>) {
+        delta.clear();
+
+        self.buckets.clear();
+        self.dists.clear();
+        self.preds.clear();
+        self.settled.clear();
+
+        self.buckets.insert(W::zero(), vec![self.base]);
+        self.dists.insert(self.base, W::zero());
+    }
 }
 
 impl<N, W: Copy> DeltaStepping<N, W> {
-    fn node<'a>(&'a self, node: N) -> DeltaSteppingNode<'a, N, W> {
+    fn node<'a>(&'a self, node: N, delta: W) -> DeltaSteppingNode<'a, N, W> {
         DeltaSteppingNode {
             node,
-            delta: self.delta,
+            delta,
+            order: self.order,
             buckets: &self.buckets,
             dists: &self.dists,
+            preds: &self.preds,
+            settled: &self.settled,
         }
     }
 }
@@ -60,6 +402,13 @@ impl<N, W> DeltaStepping<N, W> {
     /// the same values as before except for the value of delta which is updated
     /// to `delta`.
     ///
+    /// The automatic choice of delta is then disabled and the previous searches
+    /// are forgotten.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `delta` is zero.
+    ///
     /// # Example
     ///
     /// You can consult the complete version of this example at
@@ -179,13 +528,131 @@ impl<N, W> DeltaStepping<N, W> {
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn with_delta(self, delta: W) -> Self {
-        Self {
-            base: self.base,
-            delta,
-            buckets: self.buckets,
-            dists: self.dists,
-        }
+    pub fn with_delta(self, delta: W) -> Self
+    where
+        N: Copy + Eq + Hash,
+        W: Eq + Hash + Unsigned,
+    {
+        assert!(!delta.is_zero(), "The delta must be positive");
+
+        let process = Self {
+            delta: Mutex::new(Delta {
+                is_auto: false,
+                ..Delta::new(delta)
+            }),
+            ..self
+        };
+
+        process.reset();
+        process
+    }
+
+    /// Check whether the exploration is deterministic
+    ///
+    /// See [`.with_deterministic()`](Self::with_deterministic).
+    pub fn is_deterministic(&self) -> bool {
+        self.order.is_some()
+    }
+
+    /// Consumme the current [`DeltaStepping`] instance and create another with
+    /// the same values as before except for the deterministic mode which is
+    /// updated to `is_deterministic`.
+    ///
+    /// In the deterministic mode, the smallest node according to [`Ord`] is
+    /// found among the ones at the shortest distance. Likewise, the
+    /// predecessor of a node is the smallest one among the paths of the same
+    /// weight and of the fewest edges. The buckets are still explored in
+    /// parallel but the nodes reached through several shortest paths can be
+    /// explored several times.
+    ///
+    /// The previous searches are forgotten.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Weighted for Number {
+    ///     type Weight = u32;
+    ///
+    ///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+    ///         (1..=4).map(move |i| (1, Number(self.0 * 10 + i)))
+    ///     }
+    /// }
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         self.weighted_outgoing().map(|(_, node)| node)
+    ///     }
+    /// }
+    ///
+    /// let found = DeltaStepping::from_node(Number(0))
+    ///     .with_delta(1)
+    ///     .with_deterministic(true)
+    ///     .find_first(|Number(i)| i > 10 && i % 2 == 0);
+    ///
+    /// assert_eq!(found, Some(Number(12)));
+    /// ```
+    pub fn with_deterministic(self, is_deterministic: bool) -> Self
+    where
+        N: Copy + Eq + Hash + Ord,
+        W: Eq + Hash + Unsigned,
+    {
+        let process = Self {
+            order: is_deterministic.then_some(N::cmp as Order<N>),
+            ..self
+        };
+
+        process.reset();
+        process
+    }
+
+    /// Get the distance of every node discovered by the previous searches
+    ///
+    /// The distances of the nodes settled before the last search stopped are
+    /// the shortest ones. The other distances are only upper bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Weighted for Number {
+    ///     type Weight = u32;
+    ///
+    ///     fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+    ///         [(1, Number((self.0 + 1) % 5)), (3, Number(self.0 * 2 % 5))].into_iter()
+    ///     }
+    /// }
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         self.weighted_outgoing().map(|(_, node)| node)
+    ///     }
+    /// }
+    ///
+    /// let process = DeltaStepping::from_node(Number(1)).with_delta(2);
+    /// assert!(!process.contains(|_: Number| false));
+    ///
+    /// let mut distances = process.distances();
+    /// distances.sort();
+    ///
+    /// assert_eq!(distances[0], (Number(0), 4));
+    /// assert_eq!(distances[1], (Number(1), 0));
+    /// assert_eq!(distances.len(), 5);
+    /// ```
+    pub fn distances(&self) -> Vec<(N, W)>
+    where
+        N: Copy + Eq + Hash,
+        W: Copy,
+    {
+        self.dists.iter().map(|r| (*r.key(), *r.value())).collect()
     }
 }
 
@@ -197,9 +664,17 @@ where
     fn clone(&self) -> Self {
         Self {
             base: self.base,
-            delta: self.delta,
+            delta: Mutex::new(
+                self.delta
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone(),
+            ),
+            order: self.order,
             buckets: self.buckets.clone(),
             dists: self.dists.clone(),
+            preds: self.preds.clone(),
+            settled: self.settled.clone(),
         }
     }
 }
@@ -212,8 +687,14 @@ where
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(
             f,
-            "DeltaStepping {{ base: {:?}, delta: {:?}, buckets: {:?}, dists: {:?} }}",
-            self.base, self.delta, self.buckets, self.dists
+            "DeltaStepping {{ base: {:?}, delta: {:?}, is_deterministic: {:?}, buckets: {:?}, dists: {:?}, preds: {:?}, settled: {:?} }}",
+            self.base,
+            self.delta,
+            self.is_deterministic(),
+            self.buckets,
+            self.dists,
+            self.preds,
+            self.settled
         )
     }
 }
@@ -236,15 +717,19 @@ where
     type Node = N;
 
     fn from_node(node: Self::Node) -> Self {
-        let delta = W::default();
+        let delta = Mutex::new(Delta::new(W::zero()));
         let buckets = HashMultiMap::from_iter([(W::zero(), vec![node])]);
         let dists = HashMap::from_iter([(node, W::zero())]);
+        let preds = HashMap::default();
 
         Self {
             base: node,
             delta,
+            order: None,
             buckets,
             dists,
+            preds,
+            settled: HashSet::default(),
         }
     }
 }
@@ -278,66 +763,161 @@ where
     W: Copy + Default + Eq + Hash + Ord + Send + Sync + Unsigned,
 {
     fn find_first(&self, pred: P) -> Option<Self::Node> {
-        use ExploredList::*;
+        let outgoing = |node: N| {
+            node.weighted_outgoing()
+                .map(|(weight, next)| Ok::<_, Infallible>((weight, (), next)))
+        };
 
-        while let Some(first_index) = self.first_bucket_index() {
-            let mut explored_list = ExploredList::default();
+        let Ok(found) = self.search(pred, outgoing, |_, _| (), |found| found);
+        found
+    }
+}
 
-            while let Some((_, first_bucket)) = self.buckets.remove(&first_index) {
-                let mut to_append = first_bucket
-                    .into_par_iter()
-                    .fold(ExploredList::default, |mut list, node| {
-                        let to_push = self.node(node).explore(pred);
-                        list.push(to_push);
-                        list
-                    })
-                    .reduce(ExploredList::default, |mut lhs, mut rhs| {
-                        lhs.append(&mut rhs);
-                        lhs
-                    });
+impl<I, N, P, W> FindPath<I, P> for DeltaStepping<N, W>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + Weighted<Weight = W>,
+    P: Copy + Fn(I) -> bool + Send + Sync,
+    W: Copy + Default + Eq + Hash + Ord + Send + Sync + Unsigned,
+{
+    fn find_path(&self, pred: P) -> Option<Vec<Self::Node>> {
+        let outgoing = |node: N| {
+            node.weighted_outgoing()
+                .map(|(weight, next)| Ok::<_, Infallible>((weight, (), next)))
+        };
 
-                explored_list.append(&mut to_append);
-            }
+        // The path is rebuilt before another search can update the
+        // predecessors.
+        let Ok(path) = self.search(
+            pred,
+            outgoing,
+            |_, _| (),
+            |found| path_to(found, |node| self.preds.get(&node).map(|pred| pred.0)),
+        );
+        path
+    }
+}
 
-            match explored_list {
-                Solved((_, node)) => return Some(node),
-                Unsolved(heavy_edges) => {
-                    heavy_edges
-                        .into_par_iter()
-                        .flatten()
-                        .for_each(|(new_dist, node)| self.node(node).relax(new_dist));
-                }
-            }
-        }
+impl<I, L, N, P, W> FindLabels<I, P> for DeltaStepping<N, W>
+where
+    L: Send + Sync,
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + WeightedLabeled<Weight = W, Label = L>,
+    P: Copy + Fn(I) -> bool + Send + Sync,
+    W: Copy + Default + Eq + Hash + Ord + Send + Sync + Unsigned,
+{
+    type Label = L;
+
+    fn find_labels(&self, pred: P) -> Option<Vec<Self::Label>> {
+        // The previous searches did not record the labels so the search
+        // starts over with the same parameters.
+        let process = Self {
+            delta: Mutex::new(self.lock().clone()),
+            order: self.order,
+            ..Self::from_node(self.base)
+        };
+
+        let labels = HashMap::default();
+        let outgoing = |node: N| node.weighted_labeled_outgoing().map(Ok::<_, Infallible>);
+
+        let Ok(labels) = process.search(
+            pred,
+            outgoing,
+            |node, label| {
+                labels.insert(node, label);
+            },
+            |found| {
+                labels_to(found, |node| {
+                    let (_, label) = labels.remove(&node)?;
+                    Some((process.preds.get(&node)?.0, label))
+                })
+            },
+        );
+        labels
+    }
+}
+
+impl<I, N, P, W> TryContains<I, P> for DeltaStepping<N, W>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + TryWeighted<Weight = W>,
+    N::Error: Send,
+    P: Copy + Fn(I) -> bool + Send + Sync,
+    W: Copy + Default + Eq + Hash + Ord + Send + Sync + Unsigned,
+{
+    type Error = N::Error;
+
+    fn try_contains(&self, pred: P) -> Result<bool, Self::Error> {
+        self.try_find_first(pred).map(|found| found.is_some())
+    }
+}
+
+impl<I, N, P, W> TryFindAny<I, P> for DeltaStepping<N, W>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + TryWeighted<Weight = W>,
+    N::Error: Send,
+    P: Copy + Fn(I) -> bool + Send + Sync,
+    W: Copy + Default + Eq + Hash + Ord + Send + Sync + Unsigned,
+{
+    type Error = N::Error;
+
+    fn try_find_any(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+        self.try_find_first(pred)
+    }
+}
+
+impl<I, N, P, W> TryFindFirst<I, P> for DeltaStepping<N, W>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + TryWeighted<Weight = W>,
+    N::Error: Send,
+    P: Copy + Fn(I) -> bool + Send + Sync,
+    W: Copy + Default + Eq + Hash + Ord + Send + Sync + Unsigned,
+{
+    type Error = N::Error;
+
+    fn try_find_first(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+        let outgoing = |node: N| {
+            node.try_weighted_outgoing()
+                .map(|edge| edge.map(|(weight, next)| (weight, (), next)))
+        };
 
-        None
+        self.search(pred, outgoing, |_, _| (), |found| found)
     }
 }
 
 struct DeltaSteppingNode<'a, N, W> {
     node: N,
     delta: W,
+    order: Option<Order<N>>,
     buckets: &'a HashMultiMap<W, N>,
     dists: &'a HashMap<N, W>,
+    preds: &'a HashMap<N, (N, usize)>,
+    settled: &'a HashSet<N>,
 }
 
 impl<N, W> DeltaSteppingNode<'_, N, W>
 where
-    N: Copy + Eq + Hash + Weighted<Weight = W>,
+    N: Copy + Eq + Hash,
     W: Copy + Hash + Ord + Unsigned,
 {
-    fn explore<I, P>(self, pred: P) -> Explored<W, N>
+    fn explore<I, P, X, R, L, E, F>(
+        self,
+        pred: &P,
+        outgoing: &X,
+        on_pred: &F,
+    ) -> Result<Explored<W, L, N>, E>
     where
         N: ToValue<I>,
         P: Fn(I) -> bool,
+        X: Fn(N) -> R,
+        R: Iterator<Item = Result<(W, L, N), E>>,
+        F: Fn(N, L),
     {
-        use Explored::*;
-
         let Self {
             node,
             delta,
+            order,
             buckets,
             dists,
+            preds,
+            settled,
         } = self;
 
         let base_dist = self
@@ -347,29 +927,46 @@ where
             .copied()
             .unwrap_or_else(W::zero);
 
-        if pred(node.to_value()) {
-            return Solved((base_dist, node));
-        }
+        // The solved nodes are expanded as well in order to resume the search.
+        let solved = pred(node.to_value()).then_some((base_dist, node));
+        let is_settled = settled.insert(node);
+        let mut max_weight = None;
+
+        let hops = match order {
+            Some(_) => preds.get(&node).map_or(0, |pred| pred.1) + 1,
+            None => 0,
+        };
 
         let mut heavy_edges = Vec::default();
 
-        for (w, node) in node.weighted_outgoing() {
+        for edge in outgoing(node) {
+            let (w, label, next) = edge?;
             let new_dist = base_dist + w;
+            max_weight = max_weight.max(Some(w));
 
             if w > delta {
-                heavy_edges.push((new_dist, node));
+                heavy_edges.push((new_dist, (node, hops), label, next));
             } else {
                 Self {
-                    node,
-                    delta: self.delta,
+                    node: next,
+                    delta,
+                    order,
                     buckets,
                     dists,
+                    preds,
+                    settled,
                 }
-                .relax(new_dist);
+                .relax(new_dist, (node, hops), label, on_pred);
             }
         }
 
-        Unsolved(heavy_edges)
+        Ok(Explored {
+            solved,
+            heavy_edges: LinkedList::from([heavy_edges]),
+            expanded: 1,
+            settled: usize::from(is_settled),
+            max_weight,
+        })
     }
 }
 
@@ -378,21 +975,66 @@ where
     N: Copy + Eq + Hash,
     W: Copy + Hash + Ord + Unsigned,
 {
-    fn relax(self, new_dist: W) {
-        let to_insert = self
-            .dists
-            .get(&self.node)
-            .as_deref()
-            .copied()
-            .map_or(Some(new_dist), |old_dist| {
-                (new_dist < old_dist).then_some(new_dist)
-            });
+    /// Relax the edge of label `label` from `parent` to the current node
+    ///
+    /// `parent` is given with the number of edges of the path reaching the
+    /// current node through it. In the deterministic mode, this number and
+    /// then the order break the ties between the paths of the same weight.
+    /// Since both strictly decrease along the predecessors, no cycle can be
+    /// recorded even with edges of zero weight. `on_pred` is called with the
+    /// label whenever the predecessor is updated.
+    fn relax<L, F>(self, new_dist: W, parent: (N, usize), label: L, on_pred: &F)
+    where
+        F: Fn(N, L),
+    {
+        use dashmap::mapref::entry::Entry::*;
+
+        // The predecessor is updated while the distance entry is locked in
+        // order to keep both maps consistent.
+        let is_shorter = match self.dists.entry(self.node) {
+            Occupied(mut entry) if new_dist < *entry.get() => {
+                entry.insert(new_dist);
+                self.preds.insert(self.node, parent);
+                on_pred(self.node, label);
+                true
+            }
+
+            Occupied(entry) if new_dist == *entry.get() => {
+                let Some(order) = self.order else {
+                    return;
+                };
+
+                let (node, hops) = parent;
 
-        if let Some(new_dist) = to_insert {
-            self.dists.insert(self.node, new_dist);
+                let is_better = self.preds.get(&self.node).is_some_and(|pred| {
+                    hops.cmp(&pred.1)
+                        .then_with(|| order(&node, &pred.0))
+                        .is_lt()
+                });
 
+                if is_better {
+                    self.preds.insert(self.node, parent);
+                    on_pred(self.node, label);
+                }
+
+                is_better
+            }
+
+            Occupied(_) => false,
+
+            Vacant(entry) => {
+                entry.insert(new_dist);
+                self.preds.insert(self.node, parent);
+                on_pred(self.node, label);
+                true
+            }
+        };
+
+        // The delta is only zero during the first phase of an automatic delta
+        // whose light edges are of zero weight.
+        if is_shorter {
             self.buckets
-                .entry(new_dist / self.delta)
+                .entry(new_dist / cmp::max(self.delta, W::one()))
                 .or_default()
                 .push(self.node);
         }
@@ -407,64 +1049,178 @@ where
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(
             f,
-            "DeltaSteppingNode {{ node: {:?}, delta: {:?}, buckets: {:?}, dists: {:?} }}",
-            self.node, self.delta, self.buckets, self.dists
+            "DeltaSteppingNode {{ node: {:?}, delta: {:?}, is_deterministic: {:?}, buckets: {:?}, dists: {:?}, preds: {:?}, settled: {:?} }}",
+            self.node,
+            self.delta,
+            self.order.is_some(),
+            self.buckets,
+            self.dists,
+            self.preds,
+            self.settled
         )
     }
 }
 
-#[derive(Debug)]
-enum Explored<W, N> {
-    Solved((W, N)),
-    Unsolved(Vec<(W, N)>),
-}
+/// A heavy edge with the distance of its target, its source given with the
+/// number of edges of the path reaching the target through it and its label
+type HeavyEdge<W, L, N> = (W, (N, usize), L, N);
 
+/// The result of the exploration of some nodes of a bucket
 #[derive(Debug)]
-enum ExploredList<W, N> {
-    Solved((W, N)),
-    Unsolved(LinkedList<Vec<(W, N)>>),
+struct Explored<W, L, N> {
+    solved: Option<(W, N)>,
+    heavy_edges: LinkedList<Vec<HeavyEdge<W, L, N>>>,
+    expanded: usize,
+    settled: usize,
+    max_weight: Option<W>,
 }
 
-impl<W, N> ExploredList<W, N>
+impl<W, L, N> Explored<W, L, N>
 where
     N: Copy,
     W: Copy + Ord,
 {
-    pub fn append(&mut self, other: &mut Self) {
-        use ExploredList::*;
+    fn append(&mut self, other: &mut Self, order: Option<Order<N>>) {
+        self.solved = match (self.solved, other.solved) {
+            (Some(lhs), Some(rhs)) => Some(first_solved(lhs, rhs, order)),
+            (lhs, rhs) => lhs.or(rhs),
+        };
 
-        match self {
-            Solved(s) => {
-                if let &mut Solved(other) = other {
-                    *s = min_by_key(*s, other, |&(w, _)| w);
-                }
-            }
+        self.heavy_edges.append(&mut other.heavy_edges);
+        self.expanded += other.expanded;
+        self.settled += other.settled;
+        self.max_weight = self.max_weight.max(other.max_weight);
+    }
+}
 
-            Unsolved(s) => match other {
-                Solved(_) => swap(self, other),
-                Unsolved(other) => s.append(other),
-            },
+impl<W, L, N> Default for Explored<W, L, N> {
+    fn default() -> Self {
+        Self {
+            solved: None,
+            heavy_edges: LinkedList::default(),
+            expanded: 0,
+            settled: 0,
+            max_weight: None,
+        }
+    }
+}
+
+/// The value of delta shared by the searches
+///
+/// The automatic value is chosen at the end of the first phase and then
+/// adapted using the statistics of the phases since its last change.
+#[derive(Clone, Debug)]
+struct Delta<W> {
+    value: W,
+    is_auto: bool,
+    phases: usize,
+    expanded: usize,
+    settled: usize,
+    max_weight: Option<W>,
+}
+
+impl<W> Delta<W> {
+    /// Create a new automatic [`Delta`] whose current value is `value`
+    fn new(value: W) -> Self {
+        Self {
+            value,
+            is_auto: true,
+            phases: 0,
+            expanded: 0,
+            settled: 0,
+            max_weight: None,
         }
     }
 
-    pub fn push(&mut self, value: Explored<W, N>) {
-        match self {
-            Self::Solved(s) => {
-                if let Explored::Solved(value) = value {
-                    *s = min_by_key(*s, value, |&(w, _)| w);
-                }
-            }
+    /// Forget the statistics of the phases
+    fn clear_statistics(&mut self) {
+        self.phases = 0;
+        self.expanded = 0;
+        self.settled = 0;
+        self.max_weight = None;
+    }
 
-            Self::Unsolved(s) => match value {
-                Explored::Solved(value) => *self = Self::Solved(value),
-                Explored::Unsolved(value) => s.push_back(value),
-            },
+    /// Forget the automatic value and the statistics of the phases
+    fn clear(&mut self)
+    where
+        W: Zero,
+    {
+        if self.is_auto {
+            self.value = W::zero();
         }
+
+        self.clear_statistics();
     }
 }
 
-impl<W, N> Default for ExploredList<W, N> {
-    fn default() -> Self {
-        Self::Unsolved(LinkedList::default())
+impl<W: Copy + Ord + Unsigned> Delta<W> {
+    /// Adapt the automatic value after a phase
+    ///
+    /// The value only changes once the phases since the last change have
+    /// expanded at least `pending` nodes so that moving them to other buckets
+    /// is amortized.
+    ///
+    /// # Return
+    ///
+    /// `true` if the value has changed, `false` otherwise.
+    fn adapt<L, N>(
+        &mut self,
+        explored: &Explored<W, L, N>,
+        pending: usize,
+        threads: usize,
+    ) -> bool {
+        if !self.is_auto {
+            return false;
+        }
+
+        // The nodes of the first phase are at a zero distance so that the
+        // distances of its heavy edges are their weights.
+        if self.value.is_zero() {
+            let mut weights = Vec::from_iter(explored.heavy_edges.iter().flatten().map(|e| e.0));
+            let middle = weights.len() / 2;
+
+            self.value = match weights.get(middle) {
+                Some(_) => *weights.select_nth_unstable(middle).1,
+                None => W::one(),
+            };
+
+            return true;
+        }
+
+        self.phases += 1;
+        self.expanded += explored.expanded;
+        self.settled += explored.settled;
+        self.max_weight = self.max_weight.max(explored.max_weight);
+
+        if self.expanded < pending {
+            return false;
+        }
+
+        let value = self.value;
+        let max_weight = self.max_weight.unwrap_or_else(W::zero);
+
+        let is_sparse = self.expanded < self.phases * threads;
+        let is_dense = self.expanded > 2 * self.settled;
+
+        if is_sparse && value < max_weight && value < max_weight - value {
+            self.value = value + value;
+        } else if is_dense && value > W::one() {
+            self.value = value / (W::one() + W::one());
+        }
+
+        self.clear_statistics();
+        self.value != value
     }
 }
+
+/// Get the solved node at the shortest distance among `lhs` and `rhs`
+///
+/// The ties are broken using `order` if any, in favor of `lhs` otherwise.
+fn first_solved<W: Ord, N>(lhs: (W, N), rhs: (W, N), order: Option<Order<N>>) -> (W, N) {
+    let ordering = lhs
+        .0
+        .cmp(&rhs.0)
+        .then_with(|| order.map_or(cmp::Ordering::Equal, |order| order(&lhs.1, &rhs.1)));
+
+    if ordering.is_gt() { rhs } else { lhs }
+}
@@ -1,19 +1,167 @@
 //! This module contains the implementation of [`DFS`]
 
-use super::{Contains, FindAny, Process};
-use crate::{Node, ToValue};
-use std::hash::Hash;
+use super::{
+    Contains, FindAny, FindFirst, FindLabels, FindPath, Process, Reachable, TryContains,
+    TryFindAny, TryFindFirst, labels_to, path_to,
+};
+use crate::{Labeled, Node, ToValue, TryNode};
+use std::{collections::hash_map::Entry, convert::Infallible, hash::Hash};
 
 /// A [DFS](https://en.wikipedia.org/wiki/Depth-first_search) implementation of some processes
 ///
 /// In particular, the following [`Process`es](Process) are implemented:
 /// - [`Contains`],
-/// - [`FindAny`].
+/// - [`FindAny`],
+/// - [`FindFirst`],
+/// - [`FindLabels`],
+/// - [`FindPath`],
+/// - [`Reachable`],
+/// - [`TryContains`],
+/// - [`TryFindAny`],
+/// - [`TryFindFirst`].
+///
+/// [`FindFirst`] and [`TryFindFirst`] follow the depth-first pre-order which
+/// visits the outgoing nodes in their iteration order while [`FindAny`] and
+/// [`TryFindAny`] explore the last outgoing nodes first.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// struct Number(u32);
+///
+/// impl Node for Number {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         [Number(self.0 * 10 + 1), Number(self.0 * 10 + 2)]
+///             .into_iter()
+///             .filter(|Number(i)| *i < 1000)
+///     }
+/// }
+///
+/// let found = Number(0)
+///     .to_process::<DFS<_>>()
+///     .find_first(|Number(i)| i % 2 == 0);
+///
+/// assert_eq!(found, Some(Number(0)));
+///
+/// let found = Number(0)
+///     .to_process::<DFS<_>>()
+///     .find_first(|Number(i)| i > 100);
+///
+/// assert_eq!(found, Some(Number(111)));
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct DFS<N> {
     node: N,
 }
 
+/// The parent of every reached node with the label of the edge leading from it
+type Parents<N, L> = std::collections::HashMap<N, Option<(N, L)>, ahash::RandomState>;
+
+impl<N: Copy + Eq + Hash> DFS<N> {
+    /// Search for some node verifying `pred`
+    ///
+    /// `outgoing` is used to generate the outgoing nodes and the first raised
+    /// error stops the exploration.
+    fn search<I, P, X, R, E>(&self, pred: P, outgoing: X) -> Result<Option<N>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool,
+        X: Fn(N) -> R,
+        R: Iterator<Item = Result<N, E>>,
+    {
+        type HashSet<K> = std::collections::HashSet<K, ahash::RandomState>;
+
+        let mut is_visited = HashSet::default();
+        let mut to_visit = vec![self.node];
+
+        while let Some(node) = to_visit.pop() {
+            if pred(node.to_value()) {
+                return Ok(Some(node));
+            } else if is_visited.insert(node) {
+                for next in outgoing(node) {
+                    let next = next?;
+
+                    if !is_visited.contains(&next) {
+                        to_visit.push(next);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Search for the first node of the depth-first pre-order verifying
+    /// `pred`
+    ///
+    /// `outgoing` is used to generate the outgoing nodes and the first raised
+    /// error stops the exploration.
+    fn search_first<I, P, X, R, E>(&self, pred: P, outgoing: X) -> Result<Option<N>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool,
+        X: Fn(N) -> R,
+        R: Iterator<Item = Result<N, E>>,
+    {
+        type HashSet<K> = std::collections::HashSet<K, ahash::RandomState>;
+
+        let mut is_visited = HashSet::default();
+        let mut to_visit = vec![self.node];
+
+        while let Some(node) = to_visit.pop() {
+            if is_visited.insert(node) {
+                if pred(node.to_value()) {
+                    return Ok(Some(node));
+                }
+
+                let next = outgoing(node).collect::<Result<Vec<_>, _>>()?;
+                let next = next.into_iter().rev();
+
+                to_visit.extend(next.filter(|node| !is_visited.contains(node)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Search for some node verifying `pred` and record the parent of every
+    /// reached node
+    ///
+    /// `outgoing` is used to generate the labeled outgoing edges. Every parent
+    /// is recorded with the label of the edge leading from it.
+    fn search_parents<I, P, X, R, L>(&self, pred: P, outgoing: X) -> Option<(N, Parents<N, L>)>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool,
+        X: Fn(N) -> R,
+        R: Iterator<Item = (L, N)>,
+    {
+        let mut parents = Parents::default();
+        let mut to_visit = vec![(None, self.node)];
+
+        while let Some((parent, node)) = to_visit.pop() {
+            if let Entry::Vacant(entry) = parents.entry(node) {
+                entry.insert(parent);
+
+                if pred(node.to_value()) {
+                    return Some((node, parents));
+                }
+
+                let next = outgoing(node)
+                    .filter(|(_, next)| !parents.contains_key(next))
+                    .map(|(label, next)| (Some((node, label)), next));
+
+                to_visit.extend(next);
+            }
+        }
+
+        None
+    }
+}
+
 impl<N> Process for DFS<N> {
     type Node = N;
 
@@ -38,20 +186,107 @@ where
     P: Fn(I) -> bool,
 {
     fn find_any(&self, pred: P) -> Option<Self::Node> {
+        let Ok(found) = self.search(pred, |node| node.outgoing().map(Ok::<_, Infallible>));
+        found
+    }
+}
+
+impl<I, N, P> FindFirst<I, P> for DFS<N>
+where
+    N: Copy + Eq + Hash + Node + ToValue<I>,
+    P: Fn(I) -> bool,
+{
+    fn find_first(&self, pred: P) -> Option<Self::Node> {
+        let outgoing = |node: N| node.outgoing().map(Ok::<_, Infallible>);
+        let Ok(found) = self.search_first(pred, outgoing);
+        found
+    }
+}
+
+impl<I, N, P> FindPath<I, P> for DFS<N>
+where
+    N: Copy + Eq + Hash + Node + ToValue<I>,
+    P: Fn(I) -> bool,
+{
+    fn find_path(&self, pred: P) -> Option<Vec<Self::Node>> {
+        let outgoing = |node: N| node.outgoing().map(|next| ((), next));
+        let (found, parents) = self.search_parents(pred, outgoing)?;
+
+        Some(path_to(found, |node| {
+            parents[&node].as_ref().map(|(parent, _)| *parent)
+        }))
+    }
+}
+
+impl<N> Reachable for DFS<N>
+where
+    N: Copy + Eq + Hash + Node,
+{
+    fn reachable(&self) -> Vec<Self::Node> {
         type HashSet<K> = std::collections::HashSet<K, ahash::RandomState>;
 
         let mut is_visited = HashSet::default();
+        let mut reachable = Vec::default();
         let mut to_visit = vec![self.node];
 
         while let Some(node) = to_visit.pop() {
-            if pred(node.to_value()) {
-                return Some(node);
-            } else if is_visited.insert(node) {
+            if is_visited.insert(node) {
+                reachable.push(node);
+
                 let next = node.outgoing().filter(|node| !is_visited.contains(node));
                 to_visit.extend(next);
             }
         }
 
-        None
+        reachable
+    }
+}
+
+impl<I, N, P> TryContains<I, P> for DFS<N>
+where
+    N: Copy + Eq + Hash + ToValue<I> + TryNode,
+    P: Fn(I) -> bool,
+{
+    type Error = N::Error;
+
+    fn try_contains(&self, pred: P) -> Result<bool, Self::Error> {
+        self.try_find_any(pred).map(|found| found.is_some())
+    }
+}
+
+impl<I, N, P> TryFindAny<I, P> for DFS<N>
+where
+    N: Copy + Eq + Hash + ToValue<I> + TryNode,
+    P: Fn(I) -> bool,
+{
+    type Error = N::Error;
+
+    fn try_find_any(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+        self.search(pred, N::try_outgoing)
+    }
+}
+
+impl<I, N, P> TryFindFirst<I, P> for DFS<N>
+where
+    N: Copy + Eq + Hash + ToValue<I> + TryNode,
+    P: Fn(I) -> bool,
+{
+    type Error = N::Error;
+
+    fn try_find_first(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+        self.search_first(pred, N::try_outgoing)
+    }
+}
+
+impl<I, N, P> FindLabels<I, P> for DFS<N>
+where
+    N: Copy + Eq + Hash + ToValue<I> + Labeled,
+    P: Fn(I) -> bool,
+{
+    type Label = N::Label;
+
+    fn find_labels(&self, pred: P) -> Option<Vec<Self::Label>> {
+        let (found, mut parents) = self.search_parents(pred, N::labeled_outgoing)?;
+        Some(labels_to(found, |node| parents.remove(&node).flatten()))
     }
 }
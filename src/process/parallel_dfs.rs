@@ -1,26 +1,413 @@
 //! This module contains the implementation of [`ParallelDFS`]
 
-use super::{Contains, FindAny, Process};
-use crate::{Node, ToValue};
-use std::{collections::LinkedList, hash::Hash};
+use super::{
+    Contains, FindAny, FindFirst, FindLabels, FindPath, Process, Reachable, TryContains,
+    TryFindAny, TryFindFirst, labels_to, path_to,
+};
+use crate::{Labeled, Node, ToValue, TryNode};
+use rayon::{Scope, ThreadPool};
 
+use std::{
+    cmp,
+    convert::Infallible,
+    hash::Hash,
+    sync::{
+        Arc, Mutex, PoisonError, RwLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
+type HashMap<K, V> = dashmap::DashMap<K, V, ahash::RandomState>;
 type HashSet<K> = dashmap::DashSet<K, ahash::RandomState>;
 
+/// The default number of expansions after which a task splits its stack
+const DEFAULT_THRESHOLD: usize = 50_000;
+
+/// The default minimum number of pending nodes given to a new task
+const DEFAULT_CHUNK_SIZE: usize = 1;
+
 /// A parallel [DFS](https://en.wikipedia.org/wiki/Depth-first_search) implementation of some processes
 ///
 /// In particular, the following [`Process`es](Process) are implemented:
 /// - [`Contains`],
-/// - [`FindAny`].
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// - [`FindAny`],
+/// - [`FindFirst`],
+/// - [`FindLabels`],
+/// - [`FindPath`],
+/// - [`Reachable`],
+/// - [`TryContains`],
+/// - [`TryFindAny`],
+/// - [`TryFindFirst`].
+///
+/// Every task explores its own stack of pending nodes in a depth-first order.
+/// As soon as fewer tasks than threads are alive, or after
+/// [`.threshold()`](Self::threshold) expansions, a task gives the bottom half
+/// of its stack to a new task as long as it keeps at least
+/// [`.chunk_size()`](Self::chunk_size) nodes. The new tasks are then stolen by
+/// the idle threads of the [thread pool](Self::thread_pool) if any, of the
+/// current one otherwise. The whole exploration stops as soon as a task finds
+/// a node verifying the predicate.
+///
+/// Hence, the found node depends on the scheduling of the tasks unless the
+/// [deterministic mode](Self::with_deterministic) is enabled.
+///
+/// [`FindFirst`] and [`TryFindFirst`] rather return the same node as
+/// [`DFS`](super::DFS), i.e. the first one of the depth-first pre-order which
+/// visits the outgoing nodes in their iteration order. Every pending node is
+/// then given its position in this order. The tasks explore speculatively and
+/// a node reached again with an earlier position is explored again. As soon
+/// as a node verifying the predicate is found, the nodes whose position comes
+/// after it are cancelled.
+///
+/// # Example
+///
+/// ```
+/// use nodify::prelude::*;
+/// use rayon::ThreadPoolBuilder;
+/// use std::{iter::once, sync::Arc};
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Number(u32);
+///
+/// impl Node for Number {
+///     fn outgoing(self) -> impl Iterator<Item = Self> {
+///         once(Number((self.0 + 1) % 1000)).chain(once(Number(self.0 * 3 % 1000)))
+///     }
+/// }
+///
+/// let pool = Arc::new(ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+///
+/// let found = Number(1)
+///     .to_process::<ParallelDFS<_>>()
+///     .with_threshold(100)
+///     .with_chunk_size(4)
+///     .with_thread_pool(pool)
+///     .contains(|Number(i)| i == 999);
+///
+/// assert!(found);
+/// ```
+#[derive(Clone, Debug)]
 pub struct ParallelDFS<N> {
     node: N,
+    threshold: usize,
+    chunk_size: usize,
+    pool: Option<Arc<ThreadPool>>,
+    is_deterministic: bool,
+}
+
+/// The parent of every reached node with the label of the edge leading from it
+type Parents<N, L> = HashMap<N, (N, L)>;
+
+/// The reason why the exploration stopped
+enum Stop<N, E> {
+    Found(N),
+    Failed(E),
+}
+
+impl<N> ParallelDFS<N> {
+    /// Get the number of expansions after which a task splits its stack
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Get the minimum number of pending nodes given to a new task
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Get the thread pool running the exploration
+    ///
+    /// # Return
+    ///
+    /// The thread pool given to
+    /// [`.with_thread_pool()`](Self::with_thread_pool) if any, [`None`] if the
+    /// exploration runs in the current thread pool.
+    pub fn thread_pool(&self) -> Option<&ThreadPool> {
+        self.pool.as_deref()
+    }
+
+    /// Check whether the exploration is deterministic
+    ///
+    /// See [`.with_deterministic()`](Self::with_deterministic).
+    pub fn is_deterministic(&self) -> bool {
+        self.is_deterministic
+    }
+
+    /// Consumme the current instance and create another with the same values
+    /// as before except for the number of expansions after which a task splits
+    /// its stack which is updated to `threshold`.
+    ///
+    /// A lower threshold balances the load more often at the cost of more
+    /// tasks.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `threshold` is zero.
+    pub fn with_threshold(self, threshold: usize) -> Self {
+        assert!(threshold > 0, "The threshold must be positive");
+        Self { threshold, ..self }
+    }
+
+    /// Consumme the current instance and create another with the same values
+    /// as before except for the minimum number of pending nodes given to a new
+    /// task which is updated to `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `chunk_size` is zero.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "The chunk size must be positive");
+        Self { chunk_size, ..self }
+    }
+
+    /// Consumme the current instance and create another with the same values
+    /// as before except for the thread pool running the exploration which is
+    /// updated to `pool`.
+    ///
+    /// The stacks are split until there are as many tasks as threads in
+    /// `pool`.
+    pub fn with_thread_pool(self, pool: Arc<ThreadPool>) -> Self {
+        Self {
+            pool: Some(pool),
+            ..self
+        }
+    }
+
+    /// Consumme the current instance and create another with the same values
+    /// as before except for the deterministic mode which is updated to
+    /// `is_deterministic`.
+    ///
+    /// In the deterministic mode, every search follows the depth-first
+    /// pre-order used by [`FindFirst`]: the found node is the first one of
+    /// this order verifying the predicate, i.e. the same node as
+    /// [`DFS`](super::DFS), and the found path is the one of the depth-first
+    /// tree leading to it. [`Reachable`] is not affected.
+    ///
+    /// The result only depends on the order of the outgoing nodes which must
+    /// not vary between two calls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nodify::prelude::*;
+    /// use std::iter::once;
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    /// struct Number(u32);
+    ///
+    /// impl Node for Number {
+    ///     fn outgoing(self) -> impl Iterator<Item = Self> {
+    ///         once(Number((self.0 + 1) % 1000)).chain(once(Number(self.0 * 3 % 1000)))
+    ///     }
+    /// }
+    ///
+    /// let process = Number(1)
+    ///     .to_process::<ParallelDFS<_>>()
+    ///     .with_deterministic(true);
+    ///
+    /// let found = process.find_any(|Number(i)| i % 7 == 0);
+    ///
+    /// assert_eq!(found, Some(Number(7)));
+    /// assert_eq!(found, Number(1).to_process::<DFS<_>>().find_first(|Number(i)| i % 7 == 0));
+    /// assert_eq!(process.find_path(|Number(i)| i == 7).map(|path| path.len()), Some(7));
+    /// ```
+    pub fn with_deterministic(self, is_deterministic: bool) -> Self {
+        Self {
+            is_deterministic,
+            ..self
+        }
+    }
+}
+
+impl<N: Default> Default for ParallelDFS<N> {
+    fn default() -> Self {
+        Self::from_node(N::default())
+    }
+}
+
+impl<N> ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Send + Sync,
+{
+    /// Search for some node verifying `pred`
+    ///
+    /// The root is checked first. `outgoing` is used to generate the labeled
+    /// outgoing edges and the first raised error stops the exploration.
+    /// `on_edge` is called with every explored edge before checking whether
+    /// its target verifies `pred`. The expanded nodes are inserted into
+    /// `is_visited`.
+    fn search<I, P, X, R, L, E, F>(
+        &self,
+        is_visited: &HashSet<N>,
+        pred: P,
+        outgoing: X,
+        on_edge: F,
+    ) -> Result<Option<N>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool + Sync,
+        X: Fn(N) -> R + Sync,
+        R: Iterator<Item = Result<(L, N), E>>,
+        E: Send,
+        F: Fn(N, L, N) + Sync,
+    {
+        if pred(self.node.to_value()) {
+            return Ok(Some(self.node));
+        }
+
+        match &self.pool {
+            Some(pool) => pool.install(|| self.run(is_visited, &pred, &outgoing, &on_edge)),
+            None => self.run(is_visited, &pred, &outgoing, &on_edge),
+        }
+    }
+
+    /// Run [`.search()`](Self::search) in the current thread pool
+    fn run<I, P, X, R, L, E, F>(
+        &self,
+        is_visited: &HashSet<N>,
+        pred: &P,
+        outgoing: &X,
+        on_edge: &F,
+    ) -> Result<Option<N>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool + Sync,
+        X: Fn(N) -> R + Sync,
+        R: Iterator<Item = Result<(L, N), E>>,
+        E: Send,
+        F: Fn(N, L, N) + Sync,
+    {
+        let tasks = Tasks {
+            is_visited,
+            pred,
+            outgoing,
+            on_edge,
+            threshold: self.threshold,
+            chunk_size: self.chunk_size,
+            threads: rayon::current_num_threads(),
+            alive: AtomicUsize::new(1),
+            is_stopped: AtomicBool::new(false),
+            stop: Mutex::default(),
+        };
+
+        rayon::scope(|scope| tasks.explore(scope, vec![self.node]));
+
+        match tasks
+            .stop
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+        {
+            None => Ok(None),
+            Some(Stop::Found(found)) => Ok(Some(found)),
+            Some(Stop::Failed(error)) => Err(error),
+        }
+    }
+
+    /// Search for the first node of the depth-first pre-order verifying
+    /// `pred`
+    ///
+    /// `outgoing` is used to generate the labeled outgoing edges and the first
+    /// raised error of the pre-order stops the exploration. Every reached node
+    /// is inserted into `positions` with its earliest reached position and the
+    /// edge it was reached through.
+    fn search_first<I, P, X, R, L, E>(
+        &self,
+        positions: &Positions<N, L>,
+        pred: P,
+        outgoing: X,
+    ) -> Result<Option<N>, E>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool + Sync,
+        X: Fn(N) -> R + Sync,
+        R: Iterator<Item = Result<(L, N), E>>,
+        L: Send + Sync,
+        E: Send,
+    {
+        let run = || {
+            let tasks = FirstTasks {
+                positions,
+                pred: &pred,
+                outgoing: &outgoing,
+                threshold: self.threshold,
+                chunk_size: self.chunk_size,
+                threads: rayon::current_num_threads(),
+                alive: AtomicUsize::new(1),
+                next_task: AtomicUsize::new(1),
+                is_stopped: AtomicBool::new(false),
+                first: RwLock::default(),
+                stop: Mutex::default(),
+            };
+
+            let root = (self.node, Position::root(), None);
+            rayon::scope(|scope| tasks.explore(scope, 0, vec![root]));
+
+            match tasks
+                .stop
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner)
+            {
+                None => Ok(None),
+                Some(Stop::Found(found)) => Ok(Some(found)),
+                Some(Stop::Failed(error)) => Err(error),
+            }
+        };
+
+        match &self.pool {
+            Some(pool) => pool.install(run),
+            None => run(),
+        }
+    }
+
+    /// Search for some node verifying `pred` and record the parent of every
+    /// reached node
+    ///
+    /// `outgoing` is used to generate the labeled outgoing edges. Every parent
+    /// is recorded with the label of the edge leading from it. In the
+    /// deterministic mode, the parents are the ones of the depth-first tree.
+    fn search_parents<I, P, X, R, L>(&self, pred: P, outgoing: X) -> Option<(N, Parents<N, L>)>
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool + Sync,
+        X: Fn(N) -> R + Sync,
+        R: Iterator<Item = Result<(L, N), Infallible>>,
+        L: Send + Sync,
+    {
+        if self.is_deterministic {
+            let positions = HashMap::default();
+            let Ok(found) = self.search_first(&positions, pred, outgoing);
+
+            let parents = positions
+                .into_iter()
+                .filter_map(|(node, (_, parent))| Some((node, parent?)))
+                .collect();
+
+            return Some((found?, parents));
+        }
+
+        let parents = HashMap::default();
+
+        let Ok(found) = self.search(&HashSet::default(), pred, outgoing, |node, label, next| {
+            if next != self.node {
+                parents.entry(next).or_insert((node, label));
+            }
+        });
+
+        Some((found?, parents))
+    }
 }
 
 impl<N> Process for ParallelDFS<N> {
     type Node = N;
 
     fn from_node(node: Self::Node) -> Self {
-        Self { node }
+        Self {
+            node,
+            threshold: DEFAULT_THRESHOLD,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            pool: None,
+            is_deterministic: false,
+        }
     }
 }
 
@@ -40,86 +427,474 @@ where
     P: Fn(I) -> bool + Sync,
 {
     fn find_any(&self, pred: P) -> Option<Self::Node> {
-        use rayon::prelude::*;
-
-        fn next_until<I, N, P>(
-            is_visited: &HashSet<N>,
-            mut to_visit: Vec<N>,
-            threshold: usize,
-            pred: &P,
-        ) -> Result<Vec<N>, N>
-        where
-            N: Copy + Eq + Hash + ToValue<I> + Node,
-            P: Fn(I) -> bool,
-        {
-            for _ in 0..threshold {
-                match to_visit.pop() {
-                    None => break,
-
-                    Some(node) => {
-                        if is_visited.insert(node) {
-                            let next = node.outgoing().filter(|node| !is_visited.contains(node));
-
-                            for node in next {
-                                if pred(node.to_value()) {
-                                    return Err(node);
-                                } else {
-                                    to_visit.push(node);
-                                }
-                            }
-                        }
+        if self.is_deterministic {
+            return self.find_first(pred);
+        }
+
+        let outgoing = |node: N| node.outgoing().map(|next| Ok::<_, Infallible>(((), next)));
+        let Ok(found) = self.search(&HashSet::default(), pred, outgoing, |_, _, _| ());
+        found
+    }
+}
+
+impl<I, N, P> FindFirst<I, P> for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Node + Send + Sync + ToValue<I>,
+    P: Fn(I) -> bool + Sync,
+{
+    fn find_first(&self, pred: P) -> Option<Self::Node> {
+        let outgoing = |node: N| node.outgoing().map(|next| Ok::<_, Infallible>(((), next)));
+        let Ok(found) = self.search_first(&HashMap::default(), pred, outgoing);
+        found
+    }
+}
+
+impl<I, N, P> FindPath<I, P> for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Node + Send + Sync + ToValue<I>,
+    P: Fn(I) -> bool + Sync,
+{
+    fn find_path(&self, pred: P) -> Option<Vec<Self::Node>> {
+        let outgoing = |node: N| node.outgoing().map(|next| Ok::<_, Infallible>(((), next)));
+        let (found, parents) = self.search_parents(pred, outgoing)?;
+
+        Some(path_to(found, |node| {
+            parents.get(&node).map(|parent| parent.0)
+        }))
+    }
+}
+
+impl<N> Reachable for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Node + Send + Sync,
+{
+    fn reachable(&self) -> Vec<Self::Node> {
+        let is_visited = HashSet::default();
+        let outgoing = |node: N| node.outgoing().map(|next| Ok::<_, Infallible>(((), next)));
+        let Ok(_) = self.search(&is_visited, |_: N| false, outgoing, |_, _, _| ());
+        is_visited.into_iter().collect()
+    }
+}
+
+impl<I, N, P> TryContains<I, P> for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + TryNode,
+    N::Error: Send,
+    P: Fn(I) -> bool + Sync,
+{
+    type Error = N::Error;
+
+    fn try_contains(&self, pred: P) -> Result<bool, Self::Error> {
+        self.try_find_any(pred).map(|found| found.is_some())
+    }
+}
+
+impl<I, N, P> TryFindAny<I, P> for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + TryNode,
+    N::Error: Send,
+    P: Fn(I) -> bool + Sync,
+{
+    type Error = N::Error;
+
+    fn try_find_any(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+        if self.is_deterministic {
+            return self.try_find_first(pred);
+        }
+
+        let outgoing = |node: N| node.try_outgoing().map(|next| next.map(|next| ((), next)));
+        self.search(&HashSet::default(), pred, outgoing, |_, _, _| ())
+    }
+}
+
+impl<I, N, P> TryFindFirst<I, P> for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + TryNode,
+    N::Error: Send,
+    P: Fn(I) -> bool + Sync,
+{
+    type Error = N::Error;
+
+    fn try_find_first(&self, pred: P) -> Result<Option<Self::Node>, Self::Error> {
+        let outgoing = |node: N| node.try_outgoing().map(|next| next.map(|next| ((), next)));
+        self.search_first(&HashMap::default(), pred, outgoing)
+    }
+}
+
+impl<I, N, P> FindLabels<I, P> for ParallelDFS<N>
+where
+    N: Copy + Eq + Hash + Send + Sync + ToValue<I> + Labeled,
+    N::Label: Send + Sync,
+    P: Fn(I) -> bool + Sync,
+{
+    type Label = N::Label;
+
+    fn find_labels(&self, pred: P) -> Option<Vec<Self::Label>> {
+        let outgoing = |node: N| node.labeled_outgoing().map(Ok::<_, Infallible>);
+        let (found, parents) = self.search_parents(pred, outgoing)?;
+        Some(labels_to(found, |node| {
+            parents.remove(&node).map(|(_, parent)| parent)
+        }))
+    }
+}
+
+/// The state shared by the tasks of an exploration
+///
+/// See [`ParallelDFS::search()`] for the meaning of the closures.
+struct Tasks<'a, N, E, P, X, F> {
+    is_visited: &'a HashSet<N>,
+    pred: &'a P,
+    outgoing: &'a X,
+    on_edge: &'a F,
+    threshold: usize,
+    chunk_size: usize,
+    threads: usize,
+    alive: AtomicUsize,
+    is_stopped: AtomicBool,
+    stop: Mutex<Option<Stop<N, E>>>,
+}
+
+impl<N, E, P, X, F> Tasks<'_, N, E, P, X, F>
+where
+    N: Copy + Eq + Hash + Send + Sync,
+    E: Send,
+    P: Sync,
+    X: Sync,
+    F: Sync,
+{
+    /// Explore `to_visit` in a depth-first order
+    ///
+    /// The bottom half of `to_visit` is given to a new task spawned in `scope`
+    /// when fewer tasks than threads are alive or after `threshold`
+    /// expansions.
+    fn explore<'s, I, R, L>(&'s self, scope: &Scope<'s>, mut to_visit: Vec<N>)
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool,
+        X: Fn(N) -> R,
+        R: Iterator<Item = Result<(L, N), E>>,
+        F: Fn(N, L, N),
+    {
+        let mut expanded = 0;
+
+        'explore: while let Some(node) = to_visit.pop() {
+            if self.is_stopped.load(Ordering::Relaxed) {
+                break;
+            }
+
+            if !self.is_visited.insert(node) {
+                continue;
+            }
+
+            for edge in (self.outgoing)(node) {
+                let (label, next) = match edge {
+                    Ok(edge) => edge,
+
+                    Err(error) => {
+                        self.stop(Stop::Failed(error));
+                        break 'explore;
                     }
+                };
+
+                if self.is_visited.contains(&next) {
+                    continue;
+                }
+
+                (self.on_edge)(node, label, next);
+
+                if (self.pred)(next.to_value()) {
+                    self.stop(Stop::Found(next));
+                    break 'explore;
+                } else {
+                    to_visit.push(next);
                 }
             }
 
-            Ok(to_visit)
+            expanded += 1;
+
+            let is_starving = self.alive.load(Ordering::Relaxed) < self.threads;
+
+            if to_visit.len() >= 2 * self.chunk_size && (is_starving || expanded >= self.threshold)
+            {
+                let given = Vec::from_iter(to_visit.drain(..to_visit.len() / 2));
+
+                expanded = 0;
+                self.alive.fetch_add(1, Ordering::Relaxed);
+                scope.spawn(move |scope| self.explore(scope, given));
+            }
         }
 
-        let max_task = rayon::current_num_threads();
-        let threshold = 50_000;
+        self.alive.fetch_sub(1, Ordering::Relaxed);
+    }
 
-        let is_visited = HashSet::default();
-        let mut to_visit = vec![self.node];
+    /// Stop the whole exploration
+    ///
+    /// Only the first reason is kept.
+    fn stop(&self, stop: Stop<N, E>) {
+        let mut current = self.stop.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if current.is_none() {
+            *current = Some(stop);
+        }
+
+        self.is_stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+/// The position of a node in the depth-first pre-order
+///
+/// A position is the sequence of the indices of the outgoing nodes followed
+/// from the root. The positions are compared lexicographically and share
+/// their common prefix.
+struct Position {
+    parent: Option<Arc<Position>>,
+    index: usize,
+    depth: usize,
+}
+
+impl Position {
+    /// Create the position of the root
+    fn root() -> Arc<Self> {
+        Arc::new(Self {
+            parent: None,
+            index: 0,
+            depth: 0,
+        })
+    }
+
+    /// Create the position of the outgoing node of index `index`
+    fn child(self: &Arc<Self>, index: usize) -> Arc<Self> {
+        Arc::new(Self {
+            parent: Some(Arc::clone(self)),
+            index,
+            depth: self.depth + 1,
+        })
+    }
+
+    /// Get the position of the parent
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the current position is the root.
+    fn parent(&self) -> &Arc<Self> {
+        self.parent.as_ref().expect("The root has no parent")
+    }
+
+    /// Compare two positions of the same root in the pre-order
+    ///
+    /// Only the positions up to the common ancestor are walked through.
+    fn cmp(mut lhs: &Arc<Self>, mut rhs: &Arc<Self>) -> cmp::Ordering {
+        let depth = lhs.depth.cmp(&rhs.depth);
+
+        while lhs.depth > rhs.depth {
+            lhs = lhs.parent();
+        }
+
+        while rhs.depth > lhs.depth {
+            rhs = rhs.parent();
+        }
+
+        if Arc::ptr_eq(lhs, rhs) {
+            return depth;
+        }
 
-        while !to_visit.is_empty() {
-            let len = to_visit.len();
+        while !Arc::ptr_eq(lhs.parent(), rhs.parent()) {
+            lhs = lhs.parent();
+            rhs = rhs.parent();
+        }
+
+        lhs.index.cmp(&rhs.index)
+    }
+}
+
+/// [`Drop`] trait implementation for [`Position`]
+///
+/// The parents are dropped iteratively in order to support deep explorations.
+impl Drop for Position {
+    fn drop(&mut self) {
+        let mut parent = self.parent.take();
+
+        while let Some(position) = parent {
+            parent = Arc::into_inner(position).and_then(|mut position| position.parent.take());
+        }
+    }
+}
+
+/// A position with the task which reached it
+///
+/// Every task explores its positions in the pre-order. Hence, a node reached
+/// again by the same task has already been reached at an earlier position.
+type Reached = (Arc<Position>, usize);
+
+/// The earliest position of every reached node with the edge it was reached
+/// through, given by its source and its label
+type Positions<N, L> = HashMap<N, (Reached, Option<(N, L)>)>;
+
+/// A pending node with its position and the edge it was reached through
+type Pending<N, L> = (N, Arc<Position>, Option<(N, L)>);
 
-            if len < max_task {
-                let next = next_until(&is_visited, to_visit, threshold, &pred);
+/// The state shared by the tasks of a pre-order exploration
+///
+/// See [`ParallelDFS::search_first()`] for the meaning of the closures.
+struct FirstTasks<'a, N, L, E, P, X> {
+    positions: &'a Positions<N, L>,
+    pred: &'a P,
+    outgoing: &'a X,
+    threshold: usize,
+    chunk_size: usize,
+    threads: usize,
+    alive: AtomicUsize,
+    next_task: AtomicUsize,
+    is_stopped: AtomicBool,
+    first: RwLock<Option<Reached>>,
+    stop: Mutex<Option<Stop<N, E>>>,
+}
+
+impl<N, L, E, P, X> FirstTasks<'_, N, L, E, P, X>
+where
+    N: Copy + Eq + Hash + Send + Sync,
+    L: Send + Sync,
+    E: Send,
+    P: Sync,
+    X: Sync,
+{
+    /// Explore `to_visit` in the pre-order as the task `task`
+    ///
+    /// The bottom half of `to_visit` is given to a new task spawned in `scope`
+    /// when fewer tasks than threads are alive or after `threshold`
+    /// expansions.
+    fn explore<'s, I, R>(&'s self, scope: &Scope<'s>, task: usize, mut to_visit: Vec<Pending<N, L>>)
+    where
+        N: ToValue<I>,
+        P: Fn(I) -> bool,
+        X: Fn(N) -> R,
+        R: Iterator<Item = Result<(L, N), E>>,
+    {
+        let mut expanded = 0;
+
+        // The positions are popped in the pre-order so that the exploration
+        // of a task ends at the first position after the stop.
+        'explore: while let Some((node, position, parent)) = to_visit.pop() {
+            if self.is_after_stop(&position, task) {
+                break;
+            }
+
+            if !self.reach(node, &position, task, parent) {
+                continue;
+            }
+
+            if (self.pred)(node.to_value()) {
+                self.stop(position, task, Stop::Found(node));
+                break;
+            }
+
+            let mut next = Vec::default();
+
+            for (index, outgoing) in (self.outgoing)(node).enumerate() {
+                match outgoing {
+                    Ok((label, outgoing)) => {
+                        next.push((outgoing, position.child(index), Some((node, label))));
+                    }
 
-                match next {
-                    Ok(next) => to_visit = next,
-                    Err(ret) => return Some(ret),
+                    Err(error) => {
+                        self.stop(position, task, Stop::Failed(error));
+                        break 'explore;
+                    }
                 }
-            } else {
-                let next = to_visit
-                    .par_drain(len.saturating_sub(max_task)..)
-                    .chunks(1)
-                    .try_fold(LinkedList::new, |mut next, to_visit| {
-                        let to_push = next_until(&is_visited, to_visit, threshold, &pred)?;
-                        next.push_back(to_push);
-                        Ok(next)
-                    })
-                    .try_reduce(LinkedList::new, |mut lhs, mut rhs| {
-                        lhs.append(&mut rhs);
-                        Ok(lhs)
-                    })
-                    .map(|list| {
-                        list.into_iter()
-                            .reduce(|mut lhs, mut rhs| {
-                                lhs.append(&mut rhs);
-                                lhs
-                            })
-                            .unwrap_or_default()
-                    });
-
-                match next {
-                    Ok(mut next) => to_visit.append(&mut next),
-                    Err(ret) => return Some(ret),
+            }
+
+            let next = next.into_iter().rev();
+            to_visit
+                .extend(next.filter(|(node, position, _)| !self.is_reached(node, position, task)));
+
+            expanded += 1;
+
+            let is_starving = self.alive.load(Ordering::Relaxed) < self.threads;
+
+            if to_visit.len() >= 2 * self.chunk_size && (is_starving || expanded >= self.threshold)
+            {
+                let given = Vec::from_iter(to_visit.drain(..to_visit.len() / 2));
+                let given_task = self.next_task.fetch_add(1, Ordering::Relaxed);
+
+                expanded = 0;
+                self.alive.fetch_add(1, Ordering::Relaxed);
+                scope.spawn(move |scope| self.explore(scope, given_task, given));
+            }
+        }
+
+        self.alive.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Check whether `node` has been reached at a position before `position`
+    fn is_reached(&self, node: &N, position: &Arc<Position>, task: usize) -> bool {
+        self.positions.get(node).is_some_and(|reached| {
+            let ((reached, reached_task), _) = &*reached;
+            *reached_task == task || Position::cmp(reached, position).is_lt()
+        })
+    }
+
+    /// Reach `node` at `position` through the edge from `parent`
+    ///
+    /// # Return
+    ///
+    /// `true` if `node` has not been reached at a position before `position`,
+    /// `false` otherwise.
+    fn reach(
+        &self,
+        node: N,
+        position: &Arc<Position>,
+        task: usize,
+        parent: Option<(N, L)>,
+    ) -> bool {
+        use dashmap::mapref::entry::Entry::*;
+
+        match self.positions.entry(node) {
+            Occupied(mut entry) => {
+                let ((reached, reached_task), _) = entry.get();
+
+                if *reached_task == task || Position::cmp(reached, position).is_lt() {
+                    false
+                } else {
+                    entry.insert(((Arc::clone(position), task), parent));
+                    true
                 }
             }
+
+            Vacant(entry) => {
+                entry.insert(((Arc::clone(position), task), parent));
+                true
+            }
+        }
+    }
+
+    /// Check whether `position` comes after the position of the stop
+    fn is_after_stop(&self, position: &Arc<Position>, task: usize) -> bool {
+        if !self.is_stopped.load(Ordering::Acquire) {
+            return false;
+        }
+
+        let first = self.first.read().unwrap_or_else(PoisonError::into_inner);
+
+        first.as_ref().is_some_and(|(first, first_task)| {
+            *first_task == task || Position::cmp(first, position).is_lt()
+        })
+    }
+
+    /// Stop the exploration of the positions after `position`
+    ///
+    /// Only the reason of the earliest position is kept.
+    fn stop(&self, position: Arc<Position>, task: usize, stop: Stop<N, E>) {
+        let mut first = self.first.write().unwrap_or_else(PoisonError::into_inner);
+
+        let is_first = first
+            .as_ref()
+            .is_none_or(|(first, _)| Position::cmp(&position, first).is_lt());
+
+        if is_first {
+            *first = Some((position, task));
+            *self.stop.lock().unwrap_or_else(PoisonError::into_inner) = Some(stop);
         }
 
-        None
+        self.is_stopped.store(true, Ordering::Release);
     }
 }
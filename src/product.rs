@@ -0,0 +1,268 @@
+//! Products of two nodes
+//!
+//! This module contains the [node](Node) types exploring the product of the
+//! graphs of two existing [nodes](Node):
+//! - [`SyncProduct`] which is the synchronous product, and
+//! - [`InterleavedProduct`] which is the interleaving (asynchronous) product.
+//!
+//! See the documentation of the entities described just above for more
+//! information.
+
+use super::{Node, ToValue, Weighted};
+
+use std::{
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::Add,
+};
+
+/// The synchronous product of two [nodes](Node)
+///
+/// Both parts move at the same time: the outgoing nodes are all the pairs of
+/// outgoing nodes of both parts verifying the compatibility predicate. When
+/// both parts are [`Weighted`], the weight of an edge is the sum of the
+/// weights of both edges.
+///
+/// This `struct` is created by [`.sync_product()`](Node::sync_product). See its
+/// documentation for more information.
+#[derive(Clone, Copy)]
+pub struct SyncProduct<A, B, P> {
+    left: A,
+    right: B,
+    compatible: P,
+}
+
+impl<A, B, P> SyncProduct<A, B, P> {
+    /// Create a new [`SyncProduct`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `left` - The left part of the product
+    /// - `right` - The right part of the product
+    /// - `compatible` - The predicate called with the left and the right
+    ///   outgoing nodes of every edge
+    ///
+    /// # Return
+    ///
+    /// A new [`SyncProduct`] node made of `left` and `right`.
+    pub fn new(left: A, right: B, compatible: P) -> Self {
+        Self {
+            left,
+            right,
+            compatible,
+        }
+    }
+
+    /// Get the left part of the product
+    pub fn left(&self) -> &A {
+        &self.left
+    }
+
+    /// Get the right part of the product
+    pub fn right(&self) -> &B {
+        &self.right
+    }
+
+    /// Get both parts of the product
+    pub fn into_inner(self) -> (A, B) {
+        (self.left, self.right)
+    }
+}
+
+impl<A: Debug, B: Debug, P> Debug for SyncProduct<A, B, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncProduct")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`PartialEq`] implementation for [`SyncProduct`]
+///
+/// Only both parts are compared with.
+impl<A: PartialEq, B: PartialEq, P> PartialEq for SyncProduct<A, B, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.left.eq(&other.left) && self.right.eq(&other.right)
+    }
+}
+
+/// [`Eq`] implementation for [`SyncProduct`]
+///
+/// Only both parts are compared with.
+impl<A: Eq, B: Eq, P> Eq for SyncProduct<A, B, P> {}
+
+/// [`Hash`] implementation for [`SyncProduct`]
+///
+/// Only both parts are hashed.
+impl<A: Hash, B: Hash, P> Hash for SyncProduct<A, B, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.left.hash(state);
+        self.right.hash(state);
+    }
+}
+
+/// [`ToValue`] implementation for [`SyncProduct`]
+///
+/// This implementation allows casting to both parts.
+impl<A, B, P> ToValue<(A, B)> for SyncProduct<A, B, P> {
+    fn to_value(self) -> (A, B) {
+        self.into_inner()
+    }
+}
+
+/// [`Node`] implementation for [`SyncProduct`]
+///
+/// The outgoing nodes are the compatible pairs of outgoing nodes of both
+/// parts.
+impl<A, B, P> Node for SyncProduct<A, B, P>
+where
+    A: Copy + Node,
+    B: Copy + Node,
+    P: Copy + Fn(&A, &B) -> bool,
+{
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let right = Vec::from_iter(self.right.outgoing());
+
+        self.left.outgoing().flat_map(move |left| {
+            right
+                .clone()
+                .into_iter()
+                .filter(move |right| (self.compatible)(&left, right))
+                .map(move |right| Self::new(left, right, self.compatible))
+        })
+    }
+}
+
+/// [`Weighted`] implementation for [`SyncProduct`]
+///
+/// The weight of an edge is the sum of the weights of the edges of both
+/// parts.
+impl<A, B, P, W> Weighted for SyncProduct<A, B, P>
+where
+    A: Copy + Weighted<Weight = W>,
+    B: Copy + Weighted<Weight = W>,
+    P: Copy + Fn(&A, &B) -> bool,
+    W: Add<Output = W> + Copy,
+{
+    type Weight = W;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let right = Vec::from_iter(self.right.weighted_outgoing());
+
+        self.left
+            .weighted_outgoing()
+            .flat_map(move |(left_weight, left)| {
+                right
+                    .clone()
+                    .into_iter()
+                    .filter(move |(_, right)| (self.compatible)(&left, right))
+                    .map(move |(right_weight, right)| {
+                        let node = Self::new(left, right, self.compatible);
+                        (left_weight + right_weight, node)
+                    })
+            })
+    }
+}
+
+/// The interleaving (asynchronous) product of two [nodes](Node)
+///
+/// Only one part moves at a time: the outgoing nodes are the ones of the left
+/// part paired with the right part and the ones of the right part paired with
+/// the left part. When both parts are [`Weighted`], the weight of an edge is
+/// the weight of the edge of the moving part.
+///
+/// This `struct` is created by [`.interleave()`](Node::interleave). See its
+/// documentation for more information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InterleavedProduct<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> InterleavedProduct<A, B> {
+    /// Create a new [`InterleavedProduct`] node
+    ///
+    /// # Arguments
+    ///
+    /// - `left` - The left part of the product
+    /// - `right` - The right part of the product
+    ///
+    /// # Return
+    ///
+    /// A new [`InterleavedProduct`] node made of `left` and `right`.
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+
+    /// Get the left part of the product
+    pub fn left(&self) -> &A {
+        &self.left
+    }
+
+    /// Get the right part of the product
+    pub fn right(&self) -> &B {
+        &self.right
+    }
+
+    /// Get both parts of the product
+    pub fn into_inner(self) -> (A, B) {
+        (self.left, self.right)
+    }
+}
+
+/// [`ToValue`] implementation for [`InterleavedProduct`]
+///
+/// This implementation allows casting to both parts.
+impl<A, B> ToValue<(A, B)> for InterleavedProduct<A, B> {
+    fn to_value(self) -> (A, B) {
+        self.into_inner()
+    }
+}
+
+/// [`Node`] implementation for [`InterleavedProduct`]
+///
+/// The left part moves first, then the right one.
+impl<A, B> Node for InterleavedProduct<A, B>
+where
+    A: Copy + Node,
+    B: Copy + Node,
+{
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        let lefts = self
+            .left
+            .outgoing()
+            .map(move |left| Self::new(left, self.right));
+        let rights = self
+            .right
+            .outgoing()
+            .map(move |right| Self::new(self.left, right));
+
+        lefts.chain(rights)
+    }
+}
+
+/// [`Weighted`] implementation for [`InterleavedProduct`]
+///
+/// The weight of an edge is the weight of the edge of the moving part.
+impl<A, B, W> Weighted for InterleavedProduct<A, B>
+where
+    A: Copy + Weighted<Weight = W>,
+    B: Copy + Weighted<Weight = W>,
+{
+    type Weight = W;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let lefts = self
+            .left
+            .weighted_outgoing()
+            .map(move |(weight, left)| (weight, Self::new(left, self.right)));
+
+        let rights = self
+            .right
+            .weighted_outgoing()
+            .map(move |(weight, right)| (weight, Self::new(self.left, right)));
+
+        lefts.chain(rights)
+    }
+}
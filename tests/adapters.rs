@@ -0,0 +1,287 @@
+#![cfg(feature = "rayon")]
+
+use nodify::prelude::*;
+use std::convert::Infallible;
+
+/// The number of cells of the ring
+const CELLS: u32 = 12;
+
+/// A cell of a ring where every cell leads to the next one and to its
+/// quintuple
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Cell(u32);
+
+impl Cell {
+    /// Get the labeled and weighted outgoing edges
+    fn edges(self) -> [(u32, char, Self); 2] {
+        [
+            (1, '+', Cell((self.0 + 1) % CELLS)),
+            (3, '*', Cell(self.0 * 5 % CELLS)),
+        ]
+    }
+}
+
+impl Node for Cell {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.edges().into_iter().map(|(_, _, node)| node)
+    }
+}
+
+impl Weighted for Cell {
+    type Weight = u32;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.edges()
+            .into_iter()
+            .map(|(weight, _, node)| (weight, node))
+    }
+}
+
+impl Labeled for Cell {
+    type Label = char;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        self.edges()
+            .into_iter()
+            .map(|(_, label, node)| (label, node))
+    }
+}
+
+impl WeightedLabeled for Cell {
+    type Weight = u32;
+    type Label = char;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        self.edges().into_iter()
+    }
+}
+
+impl TryNode for Cell {
+    type Error = Infallible;
+
+    fn try_outgoing(self) -> impl Iterator<Item = Result<Self, Self::Error>> {
+        self.outgoing().map(Ok)
+    }
+}
+
+impl TryWeighted for Cell {
+    type Weight = u32;
+    type Error = Infallible;
+
+    fn try_weighted_outgoing(
+        self,
+    ) -> impl Iterator<Item = Result<(Self::Weight, Self), Self::Error>> {
+        self.weighted_outgoing().map(Ok)
+    }
+}
+
+impl Incoming for Cell {
+    fn incoming(self) -> impl Iterator<Item = Self> {
+        self.weighted_incoming().map(|(_, node)| node)
+    }
+}
+
+impl WeightedIncoming for Cell {
+    type Weight = u32;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        (0..CELLS).map(Cell).flat_map(move |node| {
+            node.weighted_outgoing()
+                .filter(move |&(_, next)| next == self)
+                .map(move |(weight, _)| (weight, node))
+        })
+    }
+}
+
+impl Canonical for Cell {
+    fn canonical(self) -> Self {
+        Cell(self.0.min((CELLS - self.0) % CELLS))
+    }
+}
+
+/// Check that DFS, ParallelDFS and DeltaStepping find a node verifying `$pred`
+/// from `$root`
+macro_rules! assert_explored {
+    ($root:expr, $pred:expr) => {{
+        let root = $root;
+        let pred = $pred;
+
+        let first = root.to_process::<DFS<_>>().find_first(pred);
+        assert!(first.is_some());
+
+        let process = root.to_process::<ParallelDFS<_>>();
+        assert!(process.contains(pred));
+        assert_eq!(process.find_first(pred), first);
+        assert_eq!(process.with_deterministic(true).find_any(pred), first);
+
+        let path = DeltaStepping::from_node(root).find_path(pred).unwrap();
+        assert_eq!(path.first(), Some(&root));
+        assert!(pred(path.last().unwrap().to_value()));
+    }};
+}
+
+/// Follow the edges labeled by `labels` from `root`
+fn follow<N: Labeled>(root: N, labels: &[N::Label]) -> Option<N>
+where
+    N::Label: PartialEq,
+{
+    labels.iter().try_fold(root, |node, label| {
+        node.labeled_outgoing()
+            .find(|(edge, _)| edge == label)
+            .map(|(_, next)| next)
+    })
+}
+
+/// Check that the labeled and fallible processes of DFS, ParallelDFS and
+/// DeltaStepping find a node verifying `$pred` from `$root`
+macro_rules! assert_labeled_and_fallible {
+    ($root:expr, $pred:expr) => {{
+        let root = $root;
+        let pred = $pred;
+
+        let process = root.to_process::<DFS<_>>();
+        let first = process.find_first(pred);
+        let path = process.find_path(pred).unwrap();
+        let labels = process.find_labels(pred).unwrap();
+
+        assert_eq!(process.try_find_first(pred), Ok(first));
+        assert_eq!(labels.len() + 1, path.len());
+        assert_eq!(follow(root, &labels), path.last().copied());
+
+        let process = root.to_process::<ParallelDFS<_>>();
+        assert_eq!(process.try_contains(pred), Ok(true));
+        assert_eq!(process.try_find_first(pred), Ok(first));
+
+        let labels = process.find_labels(pred).unwrap();
+        assert!(follow(root, &labels).is_some_and(|node| pred(node.to_value())));
+
+        let process = process.with_deterministic(true);
+        assert_eq!(process.try_find_any(pred), Ok(first));
+
+        let labels = process.find_labels(pred).unwrap();
+        assert_eq!(follow(root, &labels), first);
+
+        let process = DeltaStepping::from_node(root);
+        let path = process.find_path(pred).unwrap();
+        let labels = process.find_labels(pred).unwrap();
+
+        assert_eq!(process.try_find_first(pred), Ok(path.last().copied()));
+        assert_eq!(labels.len() + 1, path.len());
+        assert_eq!(follow(root, &labels), path.last().copied());
+    }};
+}
+
+#[test]
+fn canonicalized() {
+    assert_explored!(Cell(1).canonicalized(), |Cell(i)| i == 6);
+    assert_labeled_and_fallible!(Cell(1).canonicalized(), |Cell(i)| i == 6);
+}
+
+#[test]
+fn reversed() {
+    assert_explored!(Cell(1).reversed(), |Cell(i)| i == 0);
+}
+
+#[test]
+fn filter_outgoing() {
+    let root = Cell(1).filter_outgoing(|_, &Cell(next)| next % 2 == 1);
+
+    assert_explored!(root, |Cell(i)| i == 5);
+    assert_labeled_and_fallible!(root, |Cell(i)| i == 5);
+}
+
+#[test]
+fn map_weight() {
+    assert_explored!(Cell(1).map_weight(|weight| 2 * weight), |Cell(i)| i == 7);
+    assert_labeled_and_fallible!(Cell(1).map_weight(|weight| 2 * weight), |Cell(i)| i == 7);
+}
+
+#[test]
+fn unit_weights() {
+    assert_explored!(Cell(1).with_unit_weights(), |Cell(i)| i == 7);
+    assert_labeled_and_fallible!(Cell(1).with_unit_weights(), |Cell(i)| i == 7);
+}
+
+#[test]
+fn with_heuristic() {
+    assert_explored!(Cell(1).with_heuristic(|_: &Cell| 0), |Cell(i)| i == 7);
+    assert_labeled_and_fallible!(Cell(1).with_heuristic(|_: &Cell| 0), |Cell(i)| i == 7);
+}
+
+#[test]
+fn with_depth() {
+    assert_explored!(Cell(1).with_depth(), |(Cell(i), depth)| i == 1 && depth > 0);
+}
+
+#[test]
+fn take_depth() {
+    assert_explored!(Cell(1).take_depth(3), |Cell(i)| i == 4);
+
+    let reachable = Cell(1)
+        .take_depth(2)
+        .to_process::<ParallelDFS<_>>()
+        .reachable();
+    assert_eq!(reachable.len(), 1 + 2 + 4);
+}
+
+#[test]
+fn fallible_depth_adapters() {
+    let pred = |Cell(i)| i == 4;
+
+    let found = Cell(1)
+        .with_depth()
+        .to_process::<DFS<_>>()
+        .try_find_first(|(Cell(i), _)| i == 4);
+
+    assert_eq!(
+        found.map(|found| found.map(|node| node.depth())),
+        Ok(Some(3))
+    );
+
+    let process = Cell(1).take_depth(3).to_process::<ParallelDFS<_>>();
+    assert_eq!(process.try_contains(pred), Ok(true));
+
+    let process = DeltaStepping::from_node(Cell(1).take_depth(3));
+    assert!(process.try_find_first(pred).unwrap().is_some());
+
+    let process = DeltaStepping::from_node(Cell(1).take_depth(2));
+    assert_eq!(process.try_find_first(pred), Ok(None));
+}
+
+#[test]
+fn labeled_depth_adapters() {
+    let pred = |(Cell(i), _)| i == 5;
+
+    let labels = Cell(1)
+        .with_depth()
+        .to_process::<DFS<_>>()
+        .find_labels(pred);
+    assert_eq!(follow(Cell(1), &labels.unwrap()), Some(Cell(5)));
+
+    let labels = Cell(1)
+        .with_depth()
+        .to_process::<ParallelDFS<_>>()
+        .find_labels(pred);
+    assert_eq!(follow(Cell(1), &labels.unwrap()), Some(Cell(5)));
+
+    let labels = DeltaStepping::from_node(Cell(1).with_depth()).find_labels(pred);
+    assert_eq!(labels, Some(vec!['*']));
+
+    let labels = DeltaStepping::from_node(Cell(1).take_depth(1)).find_labels(|Cell(i)| i == 2);
+    assert_eq!(labels, Some(vec!['+']));
+}
+
+#[test]
+fn incoming_depth_adapters() {
+    assert_explored!(Cell(4).take_depth(3).reversed(), |node: TakeDepth<Cell>| {
+        node.into_inner() == Cell(1)
+    });
+
+    // Cell(3) is its own predecessor: only a best-first search ends here.
+    let path = DeltaStepping::from_node(Cell(4).with_depth().reversed())
+        .find_path(|node: WithDepth<Cell>| node.into_inner() == Cell(1))
+        .unwrap();
+
+    let depths = Vec::from_iter(path.iter().map(|node| node.into_inner().depth()));
+    assert_eq!(depths, Vec::from_iter(0..path.len()));
+}
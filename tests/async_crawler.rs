@@ -0,0 +1,238 @@
+#![cfg(feature = "async")]
+
+use nodify::prelude::*;
+
+use std::{
+    collections::HashSet,
+    hash::{Hash, Hasher},
+    io,
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    time,
+};
+
+/// The number of pages served by the mock server
+const PAGE_COUNT: u32 = 200;
+
+/// Get the identifiers of the pages linked by the page `id`
+fn links(id: u32) -> [u32; 2] {
+    [(2 * id + 1) % PAGE_COUNT, (3 * id + 2) % PAGE_COUNT]
+}
+
+/// A crawled page
+#[derive(Clone, Copy, Debug, Eq)]
+struct Page {
+    id: u32,
+    server: SocketAddr,
+}
+
+impl PartialEq for Page {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Hash for Page {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl ToValue<Self> for Page {
+    fn to_value(self) -> Self {
+        self
+    }
+}
+
+impl AsyncTryNode for Page {
+    type Error = io::Error;
+
+    async fn try_async_outgoing(self) -> io::Result<impl Iterator<Item = Self>> {
+        let mut stream = TcpStream::connect(self.server).await?;
+        let request = format!("GET /{} HTTP/1.0\r\n\r\n", self.id);
+        stream.write_all(request.as_bytes()).await?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+
+        let body = response
+            .strip_prefix("HTTP/1.0 200 OK\r\n\r\n")
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, response.clone()))?;
+
+        let ids = Vec::from_iter(body.lines().filter_map(|line| line.parse().ok()));
+
+        Ok(ids.into_iter().map(move |id| Self {
+            id,
+            server: self.server,
+        }))
+    }
+}
+
+/// The mock server statistics
+#[derive(Debug, Default)]
+struct Server {
+    /// The requested pages
+    requested: Mutex<HashSet<u32>>,
+
+    /// The number of requests being served
+    in_flight: AtomicUsize,
+
+    /// The maximum number of requests served at the same time
+    max_in_flight: AtomicUsize,
+
+    /// The page answered by a "404 Not Found" if any
+    missing: Option<u32>,
+}
+
+impl Server {
+    /// Start serving the mock pages on a local listener
+    async fn start(missing: Option<u32>) -> io::Result<(SocketAddr, Arc<Self>)> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let server = Arc::new(Self {
+            missing,
+            ..Self::default()
+        });
+
+        tokio::spawn(Arc::clone(&server).serve(listener));
+        Ok((addr, server))
+    }
+
+    async fn serve(self: Arc<Self>, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = Arc::clone(&self);
+
+            tokio::spawn(async move {
+                let in_flight = server.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                server.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+                let mut stream = BufReader::new(stream);
+                let mut request = String::new();
+                stream.read_line(&mut request).await?;
+
+                let id = request
+                    .split_whitespace()
+                    .nth(1)
+                    .and_then(|path| path.trim_start_matches('/').parse::<u32>().ok());
+
+                // Let the other requests pile up.
+                time::sleep(Duration::from_millis(2)).await;
+
+                let response = match id {
+                    Some(id) if id < PAGE_COUNT && Some(id) != server.missing => {
+                        server.requested.lock().unwrap().insert(id);
+                        let [lhs, rhs] = links(id);
+                        format!("HTTP/1.0 200 OK\r\n\r\n{lhs}\n{rhs}\n")
+                    }
+
+                    _ => "HTTP/1.0 404 Not Found\r\n\r\n".to_owned(),
+                };
+
+                server.in_flight.fetch_sub(1, Ordering::SeqCst);
+                stream.get_mut().write_all(response.as_bytes()).await
+            });
+        }
+    }
+}
+
+/// Get the identifiers of the pages reachable from the page `id`
+fn reachable(id: u32) -> HashSet<u32> {
+    let mut is_visited = HashSet::from([id]);
+    let mut to_visit = vec![id];
+
+    while let Some(id) = to_visit.pop() {
+        to_visit.extend(links(id).into_iter().filter(|&id| is_visited.insert(id)));
+    }
+
+    is_visited
+}
+
+#[tokio::test]
+async fn crawl_every_reachable_page() -> io::Result<()> {
+    let (addr, server) = Server::start(None).await?;
+    let root = Page {
+        id: 0,
+        server: addr,
+    };
+
+    let is_found = AsyncBFS::from_node(root)
+        .with_concurrency(4)
+        .try_contains(|_: Page| false)
+        .await?;
+
+    assert!(!is_found);
+    assert_eq!(*server.requested.lock().unwrap(), reachable(0));
+
+    let max_in_flight = server.max_in_flight.load(Ordering::SeqCst);
+    assert!(
+        (2..=4).contains(&max_in_flight),
+        "{max_in_flight} requests in flight"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_one_request_in_flight() -> io::Result<()> {
+    let (addr, server) = Server::start(None).await?;
+    let root = Page {
+        id: 0,
+        server: addr,
+    };
+
+    let found = AsyncDFS::from_node(root)
+        .with_concurrency(1)
+        .try_find_any(|Page { id, .. }| id == 17)
+        .await?;
+
+    assert_eq!(found.map(|page| page.id), Some(17));
+    assert_eq!(server.max_in_flight.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn report_a_failed_request() -> io::Result<()> {
+    let (addr, _) = Server::start(Some(5)).await?;
+    let root = Page {
+        id: 0,
+        server: addr,
+    };
+
+    let error = AsyncBFS::from_node(root)
+        .try_contains(|_: Page| false)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    Ok(())
+}
+
+#[tokio::test]
+async fn report_an_unreachable_server() -> io::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    drop(listener);
+
+    let root = Page {
+        id: 0,
+        server: addr,
+    };
+    let found = AsyncBFS::from_node(root)
+        .try_find_any(|_: Page| false)
+        .await;
+
+    assert!(found.is_err());
+    Ok(())
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "rayon")]
+
+use nodify::prelude::*;
+use nodify::process::{DeltaStepping, WeightedBidirectional};
+
+/// The number of nodes
+const COUNT: u32 = 60;
+
+/// A node whose edges have different weights in both directions
+///
+/// Several shortest paths of different numbers of edges lead to the same
+/// node so that both searches of [`WeightedBidirectional`] meet at several
+/// nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Number(u32);
+
+impl Weighted for Number {
+    type Weight = u32;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let i = self.0;
+
+        [
+            (i * 7 % 5 + 1, i + 1),
+            (i * 3 % 11 + 2, i + 3),
+            (4, i * 2),
+            (9, i.wrapping_sub(1)),
+        ]
+        .into_iter()
+        .filter(move |&(_, next)| next < COUNT && next != i)
+        .map(|(weight, next)| (weight, Number(next)))
+    }
+}
+
+impl WeightedIncoming for Number {
+    type Weight = u32;
+
+    fn weighted_incoming(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        (0..COUNT).map(Number).flat_map(move |from| {
+            from.weighted_outgoing()
+                .filter(move |&(_, to)| to == self)
+                .map(move |(weight, _)| (weight, from))
+        })
+    }
+}
+
+impl Node for Number {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.weighted_outgoing().map(|(_, node)| node)
+    }
+}
+
+/// Get the weight of `path` if it follows the lightest edges of [`Number`]
+fn weight(path: &[Number]) -> Option<u32> {
+    path.windows(2).try_fold(0, |weight, edge| {
+        let (w, _) = edge[0]
+            .weighted_outgoing()
+            .filter(|&(_, next)| next == edge[1])
+            .min()?;
+        Some(weight + w)
+    })
+}
+
+#[test]
+fn weighted_bidirectional_finds_a_shortest_path() {
+    for start in (0..COUNT).step_by(7).map(Number) {
+        let process = DeltaStepping::<_, u32>::from_node(start);
+
+        for goal in (0..COUNT).step_by(5).map(Number) {
+            let (length, path) = WeightedBidirectional::new(start, goal).find_path().unwrap();
+
+            let expected = process
+                .find_first(|node: Number| node == goal)
+                .and_then(|_| {
+                    let mut distances = process.distances().into_iter();
+                    distances.find_map(|(node, distance)| (node == goal).then_some(distance))
+                });
+
+            assert_eq!(Some(length), expected, "from {start:?} to {goal:?}");
+            assert_eq!(weight(&path), Some(length));
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+        }
+    }
+}
+
+#[test]
+fn weighted_bidirectional_misses_an_unreachable_goal() {
+    let process = WeightedBidirectional::new(Number(0), Number(COUNT));
+    assert_eq!(process.find_path(), None);
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "rayon")]
+
+use nodify::prelude::*;
+use nodify::process::DeltaStepping;
+use rayon::ThreadPoolBuilder;
+
+/// A chain whose first edge is heavier than all the following ones
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Chain(u64);
+
+impl Weighted for Chain {
+    type Weight = u64;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        let weight = if self.0 == 0 { 100 } else { 99 };
+        (self.0 < 50)
+            .then_some((weight, Chain(self.0 + 1)))
+            .into_iter()
+    }
+}
+
+impl Node for Chain {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.weighted_outgoing().map(|(_, node)| node)
+    }
+}
+
+#[test]
+fn delta_does_not_grow_past_the_largest_weight() {
+    let pool = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    pool.install(|| {
+        let process = DeltaStepping::<_, u64>::from_node(Chain(0));
+
+        assert_eq!(process.find_first(|Chain(i)| i == 50), Some(Chain(50)));
+        assert!(process.delta() <= 100);
+
+        let mut distances = process.distances();
+        distances.sort_unstable();
+
+        assert_eq!(distances.last(), Some(&(Chain(50), 100 + 49 * 99)));
+    });
+}
+
+/// A number whose edges add one or double it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Number(u32);
+
+impl Weighted for Number {
+    type Weight = u32;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        [(1, Number(self.0 + 1)), (3, Number(self.0 * 2))]
+            .into_iter()
+            .filter(|(_, Number(i))| *i <= 200)
+    }
+}
+
+impl Node for Number {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.weighted_outgoing().map(|(_, node)| node)
+    }
+}
+
+/// Get the weight of `path` if it follows the edges of [`Number`]
+fn weight(path: &[Number]) -> Option<u32> {
+    path.windows(2).try_fold(0, |weight, edge| {
+        let (w, _) = edge[0]
+            .weighted_outgoing()
+            .find(|&(_, next)| next == edge[1])?;
+        Some(weight + w)
+    })
+}
+
+#[test]
+fn concurrent_queries_share_an_instance() {
+    let process = DeltaStepping::<_, u32>::from_node(Number(1));
+    let targets = [7, 200, 3, 96, 150, 1, 64, 199];
+
+    let expected = targets.map(|target| {
+        let path = DeltaStepping::<_, u32>::from_node(Number(1))
+            .find_path(|Number(i)| i == target)
+            .unwrap();
+        weight(&path).unwrap()
+    });
+
+    std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let process = &process;
+
+            scope.spawn(move || {
+                for round in 0..20 {
+                    let index = (thread + round) % targets.len();
+                    let target = targets[index];
+
+                    if round % 7 == thread {
+                        process.reset();
+                    }
+
+                    let path = process.find_path(|Number(i)| i == target).unwrap();
+
+                    assert_eq!(path.first(), Some(&Number(1)));
+                    assert_eq!(path.last(), Some(&Number(target)));
+                    assert_eq!(weight(&path), Some(expected[index]));
+                    assert_eq!(
+                        process.find_first(|Number(i)| i == target),
+                        Some(Number(target))
+                    );
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,118 @@
+use nodify::prelude::*;
+use std::io;
+
+/// Get the message of the error raised while reading the `.gr` file `input`
+fn graph_error(input: &str) -> String {
+    let error = Graph::<u32>::read_dimacs(input.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    error.to_string()
+}
+
+/// Get the message of the error raised while reading the edge list `input`
+fn edge_list_error(input: &str) -> String {
+    let error = Graph::read_edge_list(input.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    error.to_string()
+}
+
+/// Get the message of the error raised while reading the `.co` file `input`
+fn coordinates_error(input: &str) -> String {
+    let error = Coordinates::read_dimacs(input.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    error.to_string()
+}
+
+#[test]
+fn read_a_graph() {
+    let graph = Graph::<u32>::read_dimacs("c comment\n\np sp 3 2\na 1 2 4\na 2 3 1\n".as_bytes());
+    let graph = graph.unwrap();
+
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 2);
+}
+
+#[test]
+fn reject_a_malformed_graph() {
+    let cases = [
+        (
+            "p sp 2 1\np sp 2 1\na 1 2 1\n",
+            "line 2: duplicate problem line",
+        ),
+        ("a 1 2 1\n", "line 1: missing problem line"),
+        ("p max 2 1\n", "line 1: expected a shortest path problem"),
+        ("p sp two 1\n", "line 1: invalid value `two`"),
+        (
+            "p sp 2 1\na 1 3 1\n",
+            "line 2: node identifier out of bounds",
+        ),
+        (
+            "p sp 2 1\na 0 1 1\n",
+            "line 2: node identifier out of bounds",
+        ),
+        ("p sp 2 1\na 1 2\n", "line 2: missing column"),
+        ("p sp 2 1\na 1 2 -1\n", "line 2: invalid value `-1`"),
+        ("p sp 2 1\ne 1 2 1\n", "line 2: unknown line kind"),
+        ("p sp 2\n", "line 1: missing column"),
+        ("p sp 2 2\na 1 2 1\n", "line 1: fewer arcs than declared"),
+        (
+            "p sp 2 1\na 1 2 1\na 2 1 1\n",
+            "line 3: more arcs than declared",
+        ),
+        ("p sp 18446744073709551615 0\n", "line 1: too many nodes"),
+        ("p sp 1125899906842624 0\n", "line 1: too many nodes"),
+    ];
+
+    for (input, message) in cases {
+        assert_eq!(graph_error(input), message, "{input:?}");
+    }
+}
+
+#[test]
+fn reject_malformed_coordinates() {
+    let cases = [
+        (
+            "p aux sp co 2\np aux sp co 2\n",
+            "line 2: duplicate problem line",
+        ),
+        ("v 1 0 0\n", "line 1: missing problem line"),
+        ("p aux sp 2\n", "line 1: expected a coordinate problem"),
+        (
+            "p aux sp co 2\nv 3 0 0\n",
+            "line 2: node identifier out of bounds",
+        ),
+        ("p aux sp co 2\nv 1 0\n", "line 2: missing column"),
+        (
+            "p aux sp co 2\nv 1 0 north\n",
+            "line 2: invalid value `north`",
+        ),
+        (
+            "p aux sp co 18446744073709551615\n",
+            "line 1: too many nodes",
+        ),
+        ("p aux sp co 1125899906842624\n", "line 1: too many nodes"),
+    ];
+
+    for (input, message) in cases {
+        assert_eq!(coordinates_error(input), message, "{input:?}");
+    }
+}
+
+#[test]
+fn reject_a_malformed_edge_list() {
+    let cases = [
+        (
+            "0 18446744073709551615\n",
+            "line 1: node index out of bounds",
+        ),
+        ("0 18446744073709551614\n", "line 1: too many nodes"),
+        ("0 1\n1125899906842624 0\n1 2\n", "line 2: too many nodes"),
+        ("0 one\n", "line 1: invalid value `one`"),
+    ];
+
+    for (input, message) in cases {
+        assert_eq!(edge_list_error(input), message, "{input:?}");
+    }
+
+    let error = Graph::<u32>::read_weighted_edge_list("0 18446744073709551615 1\n".as_bytes());
+    assert_eq!(error.unwrap_err().kind(), io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,100 @@
+use nodify::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Room(u32);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Door(String, u32);
+
+/// A room leads to its doors and an unbounded number of rooms can be reached
+impl DynNode for Room {
+    fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_> {
+        let doors = [
+            Door("left".to_owned(), self.0 + 1),
+            Door("right".to_owned(), self.0 * 2),
+        ];
+
+        Box::new(doors.into_iter().map(BoxedNode::new))
+    }
+}
+
+impl DynNode for Door {
+    fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_> {
+        Box::new(std::iter::once(BoxedNode::new(Room(self.1 % 20))))
+    }
+}
+
+/// The same graph where the rooms are not bounded
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Corridor(u64);
+
+impl DynNode for Corridor {
+    fn dyn_outgoing(&self) -> Box<dyn Iterator<Item = BoxedNode> + '_> {
+        Box::new(
+            std::iter::once(BoxedNode::new(Door("next".to_owned(), 0)))
+                .chain(std::iter::once(BoxedNode::new(Corridor(self.0 + 1)))),
+        )
+    }
+}
+
+#[test]
+fn dfs_explores_a_mixed_graph() {
+    let interner = DynInterner::new();
+    let root = interner.intern(BoxedNode::new(Room(1)));
+    let process = root.to_process::<DFS<_>>();
+
+    let path = process
+        .find_path(|node: DynHandle| node.downcast() == Some(Room(7)))
+        .unwrap();
+
+    assert_eq!(path.first(), Some(&root));
+    assert_eq!(path.last().unwrap().downcast(), Some(Room(7)));
+    assert!(path.iter().any(|node| node.downcast::<Door>().is_some()));
+
+    let reachable = process.reachable();
+    let materialized = BoxedNode::new(Room(1)).materialize();
+
+    assert_eq!(reachable.len(), materialized.nodes().len());
+    assert_eq!(interner.len(), reachable.len());
+}
+
+#[test]
+fn dfs_explores_an_infinite_mixed_graph() {
+    let interner = DynInterner::new();
+
+    let found = interner
+        .intern(BoxedNode::new(Corridor(0)))
+        .to_process::<DFS<_>>()
+        .find_first(|node: DynHandle| node.downcast() == Some(Corridor(100)));
+
+    assert_eq!(found.and_then(|node| node.downcast()), Some(Corridor(100)));
+    assert!(interner.len() < 1000);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_processes_explore_a_mixed_graph() {
+    let interner = DynInterner::new();
+    let root = interner.intern(BoxedNode::new(Room(1)));
+    let pred = |node: DynHandle| node.downcast() == Some(Door("right".to_owned(), 26));
+
+    let process = root.to_process::<ParallelDFS<_>>();
+    assert!(process.contains(pred));
+    assert_eq!(
+        process.reachable().len(),
+        root.to_process::<DFS<_>>().reachable().len()
+    );
+
+    let process = root.to_process::<ParallelDFS<_>>().with_deterministic(true);
+    assert_eq!(
+        process.find_any(pred),
+        root.to_process::<DFS<_>>().find_first(pred)
+    );
+
+    let process: DeltaStepping<_, usize> = root.with_unit_weights().to_process();
+    let found = process.find_first(pred).map(UnitWeights::into_inner);
+    assert_eq!(
+        found.and_then(|node| node.downcast()),
+        Some(Door("right".to_owned(), 26))
+    );
+}
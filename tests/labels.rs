@@ -0,0 +1,94 @@
+#![cfg(feature = "rayon")]
+
+use nodify::prelude::*;
+
+/// The bound of the numbers
+const MAX: u32 = 64;
+
+/// A number whose labeled edges differ from the unlabeled ones
+///
+/// The unlabeled edges add one while the labeled ones double or add three.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Number(u32);
+
+impl Number {
+    /// Get the labeled and weighted outgoing edges
+    fn edges(self) -> impl Iterator<Item = (u32, char, Self)> {
+        [(1, '*', Number(self.0 * 2)), (1, '+', Number(self.0 + 3))]
+            .into_iter()
+            .filter(|&(_, _, Number(i))| i < MAX)
+    }
+
+    /// Follow `labels` from the current number
+    fn follow(self, labels: &[char]) -> Option<Self> {
+        labels.iter().try_fold(self, |node, &label| {
+            node.edges()
+                .find(|&(_, edge, _)| edge == label)
+                .map(|(_, _, next)| next)
+        })
+    }
+}
+
+impl Node for Number {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        (self.0 + 1 < MAX).then_some(Number(self.0 + 1)).into_iter()
+    }
+}
+
+impl Weighted for Number {
+    type Weight = u32;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        self.outgoing().map(|node| (1, node))
+    }
+}
+
+impl Labeled for Number {
+    type Label = char;
+
+    fn labeled_outgoing(self) -> impl Iterator<Item = (Self::Label, Self)> {
+        self.edges().map(|(_, label, node)| (label, node))
+    }
+}
+
+impl WeightedLabeled for Number {
+    type Weight = u32;
+    type Label = char;
+
+    fn weighted_labeled_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self::Label, Self)> {
+        self.edges()
+    }
+}
+
+#[test]
+fn dfs_labels_follow_the_labeled_edges() {
+    let labels = Number(1)
+        .to_process::<DFS<_>>()
+        .find_labels(|Number(i)| i == 8)
+        .unwrap();
+
+    assert_eq!(Number(1).follow(&labels), Some(Number(8)));
+}
+
+#[test]
+fn parallel_dfs_labels_follow_the_labeled_edges() {
+    let process = Number(1).to_process::<ParallelDFS<_>>().with_threshold(2);
+
+    for process in [process.clone(), process.with_deterministic(true)] {
+        let labels = process.find_labels(|Number(i)| i == 8).unwrap();
+        assert_eq!(Number(1).follow(&labels), Some(Number(8)));
+    }
+}
+
+#[test]
+fn delta_stepping_labels_follow_the_labeled_edges() {
+    let process = DeltaStepping::from_node(Number(1));
+
+    // The unlabeled edges settle the target first.
+    assert_eq!(process.find_first(|Number(i)| i == 8), Some(Number(8)));
+    assert_eq!(
+        process.find_labels(|Number(i)| i == 8),
+        Some(vec!['+', '*'])
+    );
+    assert_eq!(process.find_labels(|Number(i)| i == 3), None);
+}
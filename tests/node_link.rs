@@ -0,0 +1,105 @@
+#![cfg(all(feature = "rayon", feature = "serde"))]
+
+use nodify::export::NodeLinkEdge;
+use nodify::prelude::*;
+use nodify::process::DeltaStepping;
+
+use std::fmt::Debug;
+
+/// A number whose edges add one or double it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Number(u32);
+
+impl Weighted for Number {
+    type Weight = u32;
+
+    fn weighted_outgoing(self) -> impl Iterator<Item = (Self::Weight, Self)> {
+        [(1, Number(self.0 + 1)), (3, Number(self.0 * 2))]
+            .into_iter()
+            .filter(|(_, Number(i))| *i <= 20)
+    }
+}
+
+impl Node for Number {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        self.weighted_outgoing().map(|(_, node)| node)
+    }
+}
+
+/// Check that `graph` is read back from both of its written formats
+fn assert_round_trip<N, W>(graph: &NodeLink<N, W>)
+where
+    N: Debug + PartialEq + serde::Serialize + serde::de::DeserializeOwned,
+    W: Debug + PartialEq + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut json = Vec::new();
+    graph.write_json(&mut json).unwrap();
+    assert_eq!(&NodeLink::read_json(json.as_slice()).unwrap(), graph);
+
+    let mut graphml = Vec::new();
+    graph.write_graphml(&mut graphml).unwrap();
+    assert_eq!(&NodeLink::read_graphml(graphml.as_slice()).unwrap(), graph);
+}
+
+#[test]
+fn a_path_round_trips() {
+    let path = DeltaStepping::from_node(Number(1))
+        .find_path(|Number(i)| i == 13)
+        .unwrap();
+
+    let graph = NodeLink::from_path(path.iter().map(|&Number(i)| i));
+
+    assert_eq!(graph.nodes.len(), path.len());
+    assert_eq!(graph.links.len(), path.len() - 1);
+    assert_round_trip(&graph);
+}
+
+#[test]
+fn a_distance_map_round_trips() {
+    let process = DeltaStepping::from_node(Number(1));
+    assert!(!process.contains(|_: Number| false));
+
+    let graph = NodeLink::from_distances(process.distances()).map_nodes(|Number(i)| i);
+
+    assert_eq!(graph.nodes.len(), 20);
+    assert!(graph.nodes.iter().all(|node| node.distance.is_some()));
+    assert_round_trip(&graph);
+}
+
+#[test]
+fn escaped_strings_round_trip() {
+    let values = ["<a>", "b & c", "\"d\" 'e'", "f\ng\\h", "]]>"];
+    let graph = NodeLink::from_path(values.map(String::from));
+
+    assert_round_trip(&graph);
+}
+
+#[test]
+fn self_closing_elements_are_read() {
+    let graphml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <graph id="G" edgedefault="directed">
+    <node id="n0"><data key="value">"a"</data></node>
+    <node id="n1"><data key="value">"b"</data></node>
+    <edge source="n0" target="n1"/>
+    <edge source="n1" target="n0"/>
+  </graph>
+</graphml>"#;
+
+    let mut expected = NodeLink::from_path(["a", "b"].map(String::from));
+    expected.links.push(NodeLinkEdge {
+        source: 1,
+        target: 0,
+        weight: (),
+    });
+
+    let graph = NodeLink::read_graphml(graphml.as_bytes()).unwrap();
+    assert_eq!(graph, expected);
+
+    let graphml = graphml.replace(
+        r#"<node id="n1"><data key="value">"b"</data></node>"#,
+        r#"<node id="n1"/>"#,
+    );
+    let error = NodeLink::<String>::read_graphml(graphml.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
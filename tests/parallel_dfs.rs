@@ -0,0 +1,54 @@
+#![cfg(feature = "rayon")]
+
+use nodify::prelude::*;
+use rayon::ThreadPoolBuilder;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Number(u32);
+
+impl Node for Number {
+    fn outgoing(self) -> impl Iterator<Item = Self> {
+        [Number((self.0 + 1) % 100), Number(self.0 * 3 % 100)].into_iter()
+    }
+}
+
+#[test]
+fn root_is_the_target() {
+    for is_deterministic in [false, true] {
+        let process = Number(0)
+            .to_process::<ParallelDFS<_>>()
+            .with_deterministic(is_deterministic);
+
+        assert!(process.contains(|Number(i)| i == 0));
+        assert_eq!(process.find_any(|Number(i)| i == 0), Some(Number(0)));
+        assert_eq!(process.find_first(|Number(i)| i == 0), Some(Number(0)));
+        assert_eq!(process.find_path(|Number(i)| i == 0), Some(vec![Number(0)]));
+    }
+}
+
+#[test]
+fn deterministic_mode_follows_the_pre_order() {
+    let pool = Arc::new(ThreadPoolBuilder::new().num_threads(4).build().unwrap());
+
+    let process = Number(1)
+        .to_process::<ParallelDFS<_>>()
+        .with_threshold(1)
+        .with_thread_pool(pool)
+        .with_deterministic(true);
+
+    let dfs = Number(1).to_process::<DFS<_>>();
+
+    for modulo in 2..20 {
+        let pred = |Number(i)| i % modulo == modulo - 1;
+        let found = dfs.find_first(pred);
+
+        assert_eq!(process.find_any(pred), found);
+        assert_eq!(
+            process
+                .find_path(pred)
+                .and_then(|path| path.last().copied()),
+            found
+        );
+    }
+}